@@ -1,4 +1,4 @@
-use nhl_rs::ClientBuilder;
+use nhl_rs::{ClientBuilder, Language};
 
 const LANGUAGE_CODE: &str = "fr";
 
@@ -12,5 +12,17 @@ async fn main() -> Result<(), u16> {
     println!("Got {:?} glossary terms", response.len());
     println!("First term {:?}", response[0].definition);
 
+    let response = client.with_language(Language::Czech).get_glossary().await?;
+    println!("First term in Czech {:?}", response[0].definition);
+
+    let response = client.get_draft_picks(2023, Some(1)).await?;
+    for pick in response.picks.iter().take(5) {
+        println!(
+            "{}: {}",
+            pick.overall_pick,
+            client.localize(&pick.team_name)
+        );
+    }
+
     Ok(())
 }
@@ -0,0 +1,31 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, ScheduleCalendar, SeasonId, TeamId};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let team_id = TeamId::new(10).unwrap();
+    let season = SeasonId::new(20232024).unwrap();
+    let games = client
+        .get_games_for_team_by_team_id(team_id)
+        .await?
+        .into_iter()
+        .filter(|game| game.season == season)
+        .collect::<Vec<_>>();
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    let mut calendar = ScheduleCalendar::new("Toronto Maple Leafs 2023-24")
+        .for_team(team_id)
+        .team_name(team_id, "Toronto Maple Leafs")
+        .venue(team_id, "Scotiabank Arena");
+    calendar.update(&games);
+
+    print!("{}", calendar.to_ics());
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, DraftRankingCategory};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let response = client.get_draft_picks(2023, Some(1)).await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    let countries = client.get_countries().await?;
+    println!("First round of the {} draft:", response.draft_year);
+    for pick in response.picks {
+        let country = pick
+            .country(&countries)
+            .map(|country| country.country_name)
+            .unwrap_or_default();
+        println!(
+            "  {}. {} ({}), {} from {:?}",
+            pick.overall_pick,
+            pick.player_name(),
+            pick.team_abbrev,
+            country,
+            pick.amateur_club_name
+        );
+    }
+
+    let response = client
+        .get_draft_rankings(2024, DraftRankingCategory::NorthAmericanGoalies)
+        .await?;
+    println!(
+        "Top North American goalies of the {} draft:",
+        response.draft_year
+    );
+    for ranking in response.rankings.iter().take(5) {
+        println!(
+            "  {:?}. {} {}",
+            ranking.final_rank, ranking.first_name, ranking.last_name
+        );
+    }
+
+    Ok(())
+}
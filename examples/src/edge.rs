@@ -0,0 +1,33 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, EdgeSelection, SeasonId, TeamId};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let selection = EdgeSelection::regular_season(SeasonId::new(20232024).unwrap());
+    let skater = client.get_skater_edge(8478402, selection).await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    if let Some(speed) = skater.skating_speed {
+        println!("Max skating speed: {:?}", speed.speed_max);
+        println!("Bursts over 20 mph: {:?}", speed.bursts_over20);
+    }
+    println!("Top shot speed: {:?}", skater.top_shot_speed);
+
+    let goalie = client.get_goalie_edge(8476945, selection).await?;
+    for location in goalie.shot_location_summary {
+        println!("{}: {:?}", location.location_code, location.save_pctg);
+    }
+
+    let team = client
+        .get_team_edge(TeamId::new(6).unwrap(), selection)
+        .await?;
+    println!("Zone time: {:?}", team.zone_time_details);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, GameId, XgModel};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let id = GameId::new(2023020204).unwrap();
+    let response = client.get_play_by_play(id).await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    let model = XgModel::default();
+    let game = model.score_game(&response);
+
+    println!("Scored {} unblocked shot attempts", game.shots.len());
+    println!(
+        "{} xG: {:.2}",
+        response.home_team.abbrev,
+        game.total_for_team(response.home_team.id)
+    );
+    println!(
+        "{} xG: {:.2}",
+        response.away_team.abbrev,
+        game.total_for_team(response.away_team.id)
+    );
+
+    Ok(())
+}
@@ -26,5 +26,13 @@ async fn main() -> Result<(), u16> {
         response
     );
 
+    let response = client.search_franchises("Montreal Canadians").await?;
+    for candidate in response.iter().take(3) {
+        println!(
+            "'Montreal Canadians' matched {} ({:.2})",
+            candidate.item.full_name, candidate.score
+        );
+    }
+
     Ok(())
 }
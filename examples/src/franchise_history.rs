@@ -0,0 +1,32 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, SeasonId};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let history = client.get_franchise_history(27).await?.unwrap();
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    println!("History of the {}:", history.franchise.full_name);
+    for era in &history.eras {
+        let seasons = match (era.first_season, era.last_season) {
+            (Some(first), Some(last)) => format!("{} to {}", first, last),
+            _ => "yet to play".to_string(),
+        };
+        println!("  {} ({}), {}", era.full_name, era.tri_code, seasons);
+    }
+
+    let season = SeasonId::new(19931994).unwrap();
+    println!(
+        "In {} the franchise played as the {}",
+        season,
+        history.name_in_season(season).unwrap_or("unknown")
+    );
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use std::time::Instant;
 
-use nhl_rs::ClientBuilder;
+use nhl_rs::{ClientBuilder, GameId, SeasonId, TeamId};
 
 #[tokio::main]
 async fn main() -> Result<(), u16> {
@@ -14,15 +14,21 @@ async fn main() -> Result<(), u16> {
 
     println!("Got {:?} NHL games", result.len());
 
-    let result = client.get_game_by_id(2017020120).await?;
+    let result = client
+        .get_game_by_id(GameId::new(2017020120).unwrap())
+        .await?;
 
     println!("Game with id 2017020120: {:?}", result);
 
-    let result = client.get_games_for_team_by_team_id(12).await?;
+    let result = client
+        .get_games_for_team_by_team_id(TeamId::new(12).unwrap())
+        .await?;
 
     println!("Team with id of 12 has: {} games", result.len());
 
-    let result = client.get_games_for_season_by_id(20222023).await?;
+    let result = client
+        .get_games_for_season_by_id(SeasonId::new(20222023).unwrap())
+        .await?;
 
     println!("2022/2023 had: {} games", result.len());
 
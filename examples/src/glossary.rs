@@ -1,6 +1,6 @@
 use std::time::Instant;
 
-use nhl_rs::ClientBuilder;
+use nhl_rs::{ClientBuilder, Language};
 
 #[tokio::main]
 async fn main() -> Result<(), u16> {
@@ -25,5 +25,14 @@ async fn main() -> Result<(), u16> {
 
     println!("Glossary item with abbreviation 'GR W': {:?}", response);
 
+    let response = client.get_glossary_items_by_any_abbreviation("B").await?;
+    for item in response {
+        println!(
+            "'B' in {:?}: {:?}",
+            item.language_of_abbreviation("B"),
+            item.full_name(&Language::English)
+        );
+    }
+
     Ok(())
 }
@@ -0,0 +1,39 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, SeasonId};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let season = SeasonId::new(19931994).unwrap();
+    let structure = client.get_league_structure(season).await?.unwrap();
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    for (division, team_ids) in structure.divisions() {
+        println!("{}: {:?}", division, team_ids);
+    }
+
+    let standings = client.get_standings_for_season(season, None).await?;
+    for table in standings.divisions(&structure) {
+        println!("{}", table.name);
+        for row in table.rows {
+            println!(
+                "  {}. {} - {} points",
+                row.rank, row.record.team_id, row.record.points
+            );
+        }
+    }
+
+    for mismatch in standings.division_mismatches(&structure) {
+        println!(
+            "Team {} ranked {} in the {}, but {} officially",
+            mismatch.team_id, mismatch.computed_rank, mismatch.division, mismatch.official_rank
+        );
+    }
+
+    Ok(())
+}
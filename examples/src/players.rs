@@ -0,0 +1,38 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, PlayerSearch};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let players = client.get_players().await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    println!("The NHL has had {} total players", players.len());
+
+    for found in PlayerSearch::new("Stutzle").matches(&players) {
+        println!(
+            "'Stutzle' matched {} ({:.2})",
+            found.item.full_name, found.score
+        );
+    }
+
+    let search = PlayerSearch::new("Sebastian Aho").birth_date("1997");
+    for found in search.matches(&players) {
+        println!(
+            "'Sebastian Aho' born in 1997: {} ({:?})",
+            found.item.full_name, found.item.position_code
+        );
+    }
+
+    let response = client.search_players("McDavid", 5, Some(true)).await?;
+    for result in response {
+        println!("Search result: {} ({:?})", result.name, result.team_abbrev);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, Transport};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let dir = std::env::temp_dir().join("nhl-rs-cassettes");
+
+    let now = Instant::now();
+
+    let client = ClientBuilder::new()
+        .transport(Transport::Record(dir.clone()))
+        .build();
+    let response = client.get_franchises().await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+    println!("Recorded {} franchises to {:?}", response.len(), dir);
+
+    let now = Instant::now();
+
+    let client = ClientBuilder::new()
+        .transport(Transport::Replay(dir))
+        .build();
+    let response = client.get_franchises().await?;
+
+    println!("Replay took {}ms", now.elapsed().as_millis());
+    println!("Replayed {} franchises", response.len());
+
+    Ok(())
+}
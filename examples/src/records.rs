@@ -0,0 +1,47 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, GameType};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let franchise = client
+        .get_franchise_by_full_name("Boston Bruins")
+        .await?
+        .unwrap();
+    let response = client
+        .get_franchise_skater_leaders(franchise.id, GameType::RegularSeason, "points", 10)
+        .await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    for (rank, leader) in response.iter().enumerate() {
+        println!(
+            "{}. {} {}: {} points",
+            rank + 1,
+            leader.first_name,
+            leader.last_name,
+            leader.points
+        );
+    }
+
+    let response = client
+        .get_franchise_team_totals(franchise.id, Some(GameType::RegularSeason))
+        .await?;
+    for totals in response {
+        println!(
+            "{}: {} games, {} wins",
+            totals.team_name, totals.games_played, totals.wins
+        );
+    }
+
+    let response = client
+        .get_franchise_season_results(franchise.id, Some(GameType::Playoffs))
+        .await?;
+    println!("{} playoff seasons", response.len());
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, Country};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let response = client.check_schema::<Country>().await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    println!("Country drift: {:?}", response);
+
+    for (resource, result) in client.check_all_schemas().await {
+        let drift = match result {
+            Ok(drift) => drift,
+            Err(status_code) => {
+                println!("{}: failed with {}", resource, status_code);
+                continue;
+            }
+        };
+        println!("{}:", resource);
+        for property in drift.new_fields {
+            println!("  new: {} ({})", property.name, property.type_field);
+        }
+        for name in drift.missing_fields {
+            println!("  missing: {}", name);
+        }
+        for field in drift.retyped_fields {
+            println!(
+                "  retyped: {} ({:?} -> {:?})",
+                field.name, field.expected, field.actual
+            );
+        }
+    }
+
+    Ok(())
+}
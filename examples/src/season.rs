@@ -0,0 +1,37 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, SeasonId};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let response = client.current_season().await?.unwrap();
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    println!(
+        "Current season: {} (game type {:?})",
+        response.season_id, response.game_type_id
+    );
+
+    let season = client
+        .get_season_by_id(SeasonId::new(20052006).unwrap())
+        .await?
+        .unwrap();
+    println!(
+        "{}: {} games, from {} to {}, shootouts: {}",
+        season.formatted_season_id,
+        season.number_of_games,
+        season.start_date,
+        season.regular_season_end_date,
+        season.rules().shootout
+    );
+
+    let response = client.get_season_ids().await?;
+    println!("The NHL has had {} seasons", response.len());
+
+    Ok(())
+}
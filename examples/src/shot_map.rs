@@ -0,0 +1,31 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, GameId, HeatMap, ShotMap, XgModel};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let id = GameId::new(2023020204).unwrap();
+    let response = client.get_play_by_play(id).await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    let game = XgModel::default().score_game(&response);
+
+    let mut heat_map = HeatMap::new(5.0);
+    for shot in &game.shots {
+        heat_map.add(shot.point, shot.xg);
+    }
+
+    let svg = ShotMap::from_game_xg(&game)
+        .heat_map(heat_map)
+        .mirror_team(response.away_team.id)
+        .render();
+
+    println!("{}", svg);
+
+    Ok(())
+}
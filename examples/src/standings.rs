@@ -0,0 +1,29 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, SeasonId};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let response = client
+        .get_standings_for_season("1993-94".parse::<SeasonId>().unwrap(), Some("1994-02-01"))
+        .await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    println!("Standings as of February 1, 1994:");
+    for row in response.league().rows {
+        println!(
+            "{}. Team {} - {} points",
+            row.rank, row.record.team_id, row.record.points
+        );
+        if let Some(tiebreaker) = row.tiebreaker {
+            println!("   {}", tiebreaker.description);
+        }
+    }
+
+    Ok(())
+}
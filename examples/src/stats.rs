@@ -0,0 +1,49 @@
+use std::time::Instant;
+
+use nhl_rs::{
+    stats::{GoalieReport, GoalieSummary, SkaterSummary},
+    ClientBuilder, GameType, SeasonId, SortDirection, StatsQuery,
+};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let query = StatsQuery::new()
+        .season(SeasonId::new(20232024).unwrap())
+        .game_type(GameType::RegularSeason)
+        .sort("points", SortDirection::Descending)
+        .limit(10);
+    let response = client.get_stats::<SkaterSummary>(&query).await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    println!("Top 10 scorers of 2023-24:");
+    for row in response.data {
+        println!("  {}: {} points", row.skater_full_name, row.points);
+    }
+
+    let query = StatsQuery::new()
+        .seasons(
+            SeasonId::new(20132014).unwrap(),
+            SeasonId::new(20232024).unwrap(),
+        )
+        .game_type(GameType::RegularSeason)
+        .aggregate(true)
+        .sort("wins", SortDirection::Descending)
+        .limit(5);
+    let response = client.get_stats::<GoalieSummary>(&query).await?;
+    println!("Most wins from 2013-14 to 2023-24:");
+    for row in response.data {
+        println!("  {}: {:?} wins", row.goalie_full_name, row.wins);
+    }
+
+    let response = client
+        .get_goalie_report(GoalieReport::DaysRest, &query)
+        .await?;
+    println!("Days rest report has {} rows", response.total);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use std::time::Instant;
+
+use nhl_rs::{ClientBuilder, GameId, GameSide, TeamId};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let teams = client.get_teams().await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    println!("The NHL has had {} total teams", teams.len());
+
+    let response = client.get_team_by_tri_code("TOR").await?.unwrap();
+    println!("Team with the tri-code of 'TOR': {:?}", response);
+
+    let game = client
+        .get_game_by_id(GameId::new(2017020120).unwrap())
+        .await?
+        .unwrap();
+    let response = client.team_for_game_side(&game, GameSide::Visiting).await?;
+    println!("Visiting team of game 2017020120: {:?}", response);
+
+    let franchise = client
+        .get_franchise_by_team_id(TeamId::new(32).unwrap())
+        .await?
+        .unwrap();
+    println!("Franchise of the Quebec Nordiques: {}", franchise.full_name);
+    for team in franchise.teams(&teams) {
+        println!("  {} ({})", team.full_name, team.tri_code);
+    }
+
+    Ok(())
+}
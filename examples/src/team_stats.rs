@@ -0,0 +1,43 @@
+use std::time::Instant;
+
+use nhl_rs::{
+    stats::{TeamGoalsByPeriod, TeamSummary},
+    ClientBuilder, GameType, SeasonId, SortDirection, StatsQuery,
+};
+
+#[tokio::main]
+async fn main() -> Result<(), u16> {
+    let now = Instant::now();
+
+    let client = ClientBuilder::new().build();
+
+    let query = StatsQuery::new()
+        .season(SeasonId::new(20232024).unwrap())
+        .game_type(GameType::RegularSeason)
+        .sort("points", SortDirection::Descending)
+        .limit(5);
+    let response = client.get_stats::<TeamSummary>(&query).await?;
+
+    println!("Request took {}ms", now.elapsed().as_millis());
+
+    println!("Top 5 teams of 2023-24:");
+    for row in response.data {
+        println!("  {}: {:?} points", row.team_full_name, row.points);
+    }
+
+    let query = StatsQuery::new()
+        .dates("2024-01-01", "2024-01-31")
+        .game_type(GameType::RegularSeason)
+        .aggregate(true)
+        .sort("teamFullName", SortDirection::Ascending);
+    let response = client.get_stats::<TeamGoalsByPeriod>(&query).await?;
+    println!("Third period goals in January 2024:");
+    for row in response.data {
+        println!(
+            "  {}: {:?} for, {:?} against",
+            row.team_full_name, row.period_3_goals_for, row.period_3_goals_against
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! Generates the Rust structs of a stats entity from its `/meta` JSON.
+//!
+//! ```bash
+//! curl -s https://api.nhle.com/stats/rest/en/country/meta | cargo run --bin nhl-rs-codegen
+//! cargo run --bin nhl-rs-codegen -- country-meta.json > src/country.rs
+//! ```
+
+use std::{
+    env, fs,
+    io::{self, Read},
+    process,
+};
+
+use nhl_rs::{codegen, GameMetadataResponse};
+
+fn main() {
+    let path = env::args().nth(1).filter(|path| path != "-");
+    let json = match &path {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut json = String::new();
+            io::stdin().read_to_string(&mut json).map(|_| json)
+        }
+    };
+    let json = json.unwrap_or_else(|error| {
+        eprintln!("Failed to read the metadata: {}", error);
+        process::exit(1);
+    });
+
+    let metadata = serde_json::from_str::<GameMetadataResponse>(&json).unwrap_or_else(|error| {
+        eprintln!("Failed to parse the metadata: {}", error);
+        process::exit(1);
+    });
+
+    print!("{}", codegen::generate(&metadata));
+}
@@ -0,0 +1,371 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::SystemTime,
+};
+
+use crate::{
+    date::{civil_from_days, unix_seconds},
+    game::{Game, GameScheduleState, GameState},
+    ids::{GameId, TeamId},
+};
+
+/// Time zone of `Game.eastern_start_time`.
+const TIME_ZONE: &str = "America/New_York";
+/// Definition of [`TIME_ZONE`], using the daylight saving time rules in effect from 1987 to 2006
+/// and since 2007. Start times of games before 1987 may be off by an hour.
+const VTIMEZONE: [&str; 31] = [
+    "BEGIN:VTIMEZONE",
+    "TZID:America/New_York",
+    "BEGIN:DAYLIGHT",
+    "TZOFFSETFROM:-0500",
+    "TZOFFSETTO:-0400",
+    "TZNAME:EDT",
+    "DTSTART:19870405T020000",
+    "RRULE:FREQ=YEARLY;BYMONTH=4;BYDAY=1SU;UNTIL=20060402T070000Z",
+    "END:DAYLIGHT",
+    "BEGIN:STANDARD",
+    "TZOFFSETFROM:-0400",
+    "TZOFFSETTO:-0500",
+    "TZNAME:EST",
+    "DTSTART:19871025T020000",
+    "RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU;UNTIL=20061029T060000Z",
+    "END:STANDARD",
+    "BEGIN:DAYLIGHT",
+    "TZOFFSETFROM:-0500",
+    "TZOFFSETTO:-0400",
+    "TZNAME:EDT",
+    "DTSTART:20070311T020000",
+    "RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU",
+    "END:DAYLIGHT",
+    "BEGIN:STANDARD",
+    "TZOFFSETFROM:-0400",
+    "TZOFFSETTO:-0500",
+    "TZNAME:EST",
+    "DTSTART:20071104T020000",
+    "RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU",
+    "END:STANDARD",
+    "END:VTIMEZONE",
+];
+/// Expected length of a game.
+const GAME_DURATION: &str = "PT3H";
+/// Maximum length of a content line in octets, excluding the line break.
+const MAX_LINE_LENGTH: usize = 75;
+
+#[derive(Debug, Clone, PartialEq)]
+struct CalendarEvent {
+    game: Game,
+    sequence: u32,
+}
+
+/// An RFC 5545 iCalendar of games, e.g. a team's schedule.
+///
+/// Each game is an event with a stable UID derived from its id, so calendar applications update
+/// existing events instead of duplicating them. Feeding newer games to [`ScheduleCalendar::update`]
+/// bumps the `SEQUENCE` of rescheduled, postponed or cancelled games.
+///
+/// Sequences only live in the calendar, so a calendar built from scratch for every request
+/// starts each game at `SEQUENCE:0`. To keep sequences across requests, persist the published
+/// games with [`ScheduleCalendar::sequences`] and [`ScheduleCalendar::restore`] them before
+/// updating the calendar with the latest games.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{Game, GameId, ScheduleCalendar, TeamId};
+///
+/// let leafs = TeamId::new(10).unwrap();
+/// let habs = TeamId::new(8).unwrap();
+/// let mut game = Game {
+///     id: GameId::new(2017020120).unwrap(),
+///     eastern_start_time: "2017-10-21T19:00:00".to_string(),
+///     game_date: "2017-10-21".to_string(),
+///     home_team_id: habs,
+///     visiting_team_id: leafs,
+///     ..Default::default()
+/// };
+///
+/// let mut calendar = ScheduleCalendar::new("Maple Leafs")
+///     .for_team(leafs)
+///     .team_name(leafs, "Toronto Maple Leafs")
+///     .team_name(habs, "Montréal Canadiens");
+/// calendar.update(&[game.clone()]);
+///
+/// game.eastern_start_time = "2017-10-21T20:00:00".to_string();
+/// calendar.update(&[game]);
+///
+/// let ics = calendar.to_ics();
+/// assert!(ics.contains("UID:2017020120@nhl-rs"));
+/// assert!(ics.contains("SUMMARY:Toronto Maple Leafs @ Montréal Canadiens"));
+/// assert!(ics.contains("DTSTART;TZID=America/New_York:20171021T200000"));
+/// assert!(ics.contains("SEQUENCE:1"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScheduleCalendar {
+    name: String,
+    team: Option<TeamId>,
+    team_names: HashMap<TeamId, String>,
+    venues: HashMap<TeamId, String>,
+    events: BTreeMap<GameId, CalendarEvent>,
+}
+
+impl ScheduleCalendar {
+    /// Creates an empty calendar named `name`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Writes summaries from a team's point of view, e.g. `Maple Leafs vs Canadiens` for home
+    /// games. Without a team, summaries are written as `Away @ Home`.
+    pub fn for_team(mut self, team_id: TeamId) -> Self {
+        self.team = Some(team_id);
+        self
+    }
+
+    /// Sets the name used for a team in summaries. Defaults to `Team {id}`.
+    pub fn team_name(mut self, team_id: TeamId, name: &str) -> Self {
+        self.team_names.insert(team_id, name.to_string());
+        self
+    }
+
+    /// Sets the venue (used as the event location) of a team's home games.
+    pub fn venue(mut self, team_id: TeamId, venue: &str) -> Self {
+        self.venues.insert(team_id, venue.to_string());
+        self
+    }
+
+    /// Adds new games and updates existing ones.
+    ///
+    /// The sequence of a game already in the calendar is incremented when its date, start time
+    /// or status has changed. Game state changes that keep the status, e.g. a game going live,
+    /// leave the sequence unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{Game, GameId, GameScheduleState, GameState, ScheduleCalendar};
+    ///
+    /// let mut game = Game {
+    ///     id: GameId::new(2017020120).unwrap(),
+    ///     eastern_start_time: "2017-10-21T19:00:00".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let mut calendar = ScheduleCalendar::new("NHL");
+    /// calendar.update(&[game.clone()]);
+    ///
+    /// game.game_state_id = GameState::Live;
+    /// calendar.update(&[game.clone()]);
+    /// assert_eq!(calendar.sequences()[&game.id], 0);
+    ///
+    /// game.game_schedule_state_id = GameScheduleState::Postponed;
+    /// calendar.update(&[game.clone()]);
+    /// assert_eq!(calendar.sequences()[&game.id], 1);
+    /// ```
+    pub fn update(&mut self, games: &[Game]) {
+        for game in games {
+            match self.events.get_mut(&game.id) {
+                Some(event) => {
+                    if event.game.eastern_start_time != game.eastern_start_time
+                        || event.game.game_date != game.game_date
+                        || status(&event.game) != status(game)
+                    {
+                        event.sequence += 1;
+                    }
+                    event.game = game.clone();
+                }
+                None => {
+                    self.events.insert(
+                        game.id,
+                        CalendarEvent {
+                            game: game.clone(),
+                            sequence: 0,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Adds a game as previously published with `sequence`, e.g. from a stored calendar, so
+    /// [`ScheduleCalendar::update`] bumps its sequence from there.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{Game, GameId, ScheduleCalendar};
+    ///
+    /// let mut game = Game {
+    ///     id: GameId::new(2017020120).unwrap(),
+    ///     eastern_start_time: "2017-10-21T19:00:00".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut calendar = ScheduleCalendar::new("NHL");
+    /// calendar.restore(&game, 3);
+    ///
+    /// game.eastern_start_time = "2017-10-21T20:00:00".to_string();
+    /// calendar.update(&[game.clone()]);
+    ///
+    /// assert_eq!(calendar.sequences()[&game.id], 4);
+    /// assert!(calendar.to_ics().contains("SEQUENCE:4"));
+    /// ```
+    pub fn restore(&mut self, game: &Game, sequence: u32) {
+        self.events.insert(
+            game.id,
+            CalendarEvent {
+                game: game.clone(),
+                sequence,
+            },
+        );
+    }
+
+    /// The sequence of every game in the calendar, to persist along with the games.
+    pub fn sequences(&self) -> BTreeMap<GameId, u32> {
+        self.events
+            .iter()
+            .map(|(id, event)| (*id, event.sequence))
+            .collect()
+    }
+
+    /// Renders the calendar in the iCalendar (`.ics`) format.
+    pub fn to_ics(&self) -> String {
+        let stamp = utc_timestamp(SystemTime::now());
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//nhl-rs//Schedule//EN".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+            format!("X-WR-CALNAME:{}", escape(&self.name)),
+            format!("X-WR-TIMEZONE:{}", TIME_ZONE),
+        ];
+        lines.extend(VTIMEZONE.iter().map(|line| line.to_string()));
+
+        for event in self.events.values() {
+            self.render_event(event, &stamp, &mut lines);
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        lines
+            .iter()
+            .map(|line| fold(line))
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    fn render_event(&self, event: &CalendarEvent, stamp: &str, lines: &mut Vec<String>) {
+        let game = &event.game;
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@nhl-rs", game.id));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!("SEQUENCE:{}", event.sequence));
+
+        match local_date_time(&game.eastern_start_time) {
+            Some(start) => {
+                lines.push(format!("DTSTART;TZID={}:{}", TIME_ZONE, start));
+                lines.push(format!("DURATION:{}", GAME_DURATION));
+            }
+            None => {
+                let date = game.game_date.replace('-', "");
+                lines.push(format!(
+                    "DTSTART;VALUE=DATE:{}",
+                    date.get(..8).unwrap_or(&date)
+                ));
+            }
+        }
+
+        lines.push(format!("SUMMARY:{}", escape(&self.summary(game))));
+        if let Some(venue) = self.venues.get(&game.home_team_id) {
+            lines.push(format!("LOCATION:{}", escape(venue)));
+        }
+
+        lines.push(format!("STATUS:{}", status(game)));
+        lines.push("END:VEVENT".to_string());
+    }
+
+    fn summary(&self, game: &Game) -> String {
+        let name = |team_id: TeamId| {
+            self.team_names
+                .get(&team_id)
+                .cloned()
+                .unwrap_or_else(|| format!("Team {}", team_id))
+        };
+
+        match self.team {
+            Some(team) if team == game.home_team_id => {
+                format!("{} vs {}", name(team), name(game.visiting_team_id))
+            }
+            Some(team) if team == game.visiting_team_id => {
+                format!("{} @ {}", name(team), name(game.home_team_id))
+            }
+            _ => format!(
+                "{} @ {}",
+                name(game.visiting_team_id),
+                name(game.home_team_id)
+            ),
+        }
+    }
+}
+
+/// The iCalendar status of a game's event.
+fn status(game: &Game) -> &'static str {
+    match (game.game_schedule_state_id, game.game_state_id) {
+        (GameScheduleState::Cancelled, _) => "CANCELLED",
+        (GameScheduleState::Postponed | GameScheduleState::ToBeDetermined, _)
+        | (_, GameState::Postponed | GameState::ScheduledTimeTbd) => "TENTATIVE",
+        _ => "CONFIRMED",
+    }
+}
+
+/// Converts `YYYY-MM-DDTHH:MM:SS` to the iCalendar local date time form `YYYYMMDDTHHMMSS`.
+fn local_date_time(date_time: &str) -> Option<String> {
+    let (date, time) = date_time.split_once('T')?;
+    let date = date.replace('-', "");
+    let time = time.get(..8)?.replace(':', "");
+    if date.len() != 8 || time.len() != 6 {
+        return None;
+    }
+    if !date.chars().chain(time.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("{}T{}", date, time))
+}
+
+/// Formats a time as an iCalendar UTC date time, e.g. `20240101T120000Z`.
+fn utc_timestamp(time: SystemTime) -> String {
+    let seconds = unix_seconds(time);
+    let (days, seconds_of_day) = (seconds.div_euclid(86400), seconds.rem_euclid(86400));
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+/// Escapes a TEXT value.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Folds a content line into lines of at most [`MAX_LINE_LENGTH`] octets, each ending in CRLF.
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > MAX_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
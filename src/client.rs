@@ -1,7 +1,10 @@
+use crate::{http::Transport, language::Language};
+
 /// A builder used for constructing a [`Client`]. Constructed using [`ClientBuilder::new`].
 #[derive(Debug, Default)]
 pub struct ClientBuilder {
-    language: Option<String>,
+    language: Option<Language>,
+    transport: Transport,
 }
 
 impl ClientBuilder {
@@ -10,18 +13,35 @@ impl ClientBuilder {
         Self::default()
     }
 
-    /// Sets the language code for the client.
+    /// Sets the language of the client, as a [`Language`] or a language code.
     ///
     /// # Example
     /// ```rust
-    /// use nhl_rs::ClientBuilder;
+    /// use nhl_rs::{ClientBuilder, Language};
     ///
     /// const LANGUAGE_CODE: &str = "fr";
     ///
     /// let client = ClientBuilder::new().language(LANGUAGE_CODE.to_string()).build();
+    /// let client = ClientBuilder::new().language(Language::French).build();
     /// ```
-    pub fn language(mut self, language: String) -> Self {
-        self.language = Some(language);
+    pub fn language(mut self, language: impl Into<Language>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Sets how the client sends requests: live (the default), recording responses to a
+    /// cassette directory or replaying them from it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use nhl_rs::{ClientBuilder, Transport};
+    ///
+    /// let client = ClientBuilder::new()
+    ///     .transport(Transport::Replay("tests/cassettes".into()))
+    ///     .build();
+    /// ```
+    pub fn transport(mut self, transport: Transport) -> Self {
+        self.transport = transport;
         self
     }
 
@@ -35,10 +55,12 @@ impl ClientBuilder {
     /// ```
     pub fn build(self) -> Client {
         Client {
-            language: self.language.unwrap_or("en".to_string()),
+            language: self.language.unwrap_or_default(),
             base_url: "https://api-web.nhle.com".to_string(),
             stats_base_url: "https://api.nhle.com/stats/rest".to_string(),
-            ..Default::default()
+            search_base_url: "https://search.d3.nhle.com/api/v1/search".to_string(),
+            records_base_url: "https://records.nhl.com/site/api".to_string(),
+            transport: self.transport,
         }
     }
 }
@@ -67,9 +89,43 @@ impl ClientBuilder {
 /// let client = ClientBuilder::new().language(LANGUAGE_CODE.to_string()).build();
 /// ```
 ///
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Client {
-    pub(crate) language: String,
+    pub(crate) language: Language,
     pub(crate) base_url: String,
     pub(crate) stats_base_url: String,
+    pub(crate) search_base_url: String,
+    pub(crate) records_base_url: String,
+    pub(crate) transport: Transport,
+}
+
+impl Client {
+    /// The language of the client.
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+
+    /// A copy of the client using another language, e.g. for a single request.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, Language};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.with_language(Language::French).get_glossary().await?;
+    ///
+    /// println!("{:?}", response[0]);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub fn with_language(&self, language: impl Into<Language>) -> Client {
+        Client {
+            language: language.into(),
+            ..self.clone()
+        }
+    }
 }
@@ -0,0 +1,192 @@
+use std::fmt::Write;
+
+use crate::{
+    game::{Entity, GameMetadataResponse, Property},
+    schema::FieldType,
+};
+
+/// Rust keywords that can't be used as field names.
+const KEYWORDS: [&str; 38] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+/// Generates the Rust module of a stats entity from its `/meta`: a struct with a field per
+/// property and the `{data, total}` response wrapping it.
+///
+/// Properties are typed from their `type` and `format`, and relationships become `Option<i64>`
+/// id fields, e.g. `franchise` becomes `franchise_id`. Every field but `id` is optional, as
+/// `/meta` doesn't tell which properties can be `null`.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{codegen, GameMetadataResponse};
+///
+/// let metadata: GameMetadataResponse = serde_json::from_str(
+///     r#"{
+///         "entity": {
+///             "name": "Team",
+///             "properties": [
+///                 {"name": "id", "type": "integer", "format": "int64"},
+///                 {"name": "franchise", "type": "integer", "relationship": true},
+///                 {"name": "fullName", "type": "string"},
+///                 {"name": "pointForOTLossInUse", "type": "boolean"},
+///                 {"name": "type", "type": "string"}
+///             ]
+///         },
+///         "links": []
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// let code = codegen::generate(&metadata);
+///
+/// assert!(code.contains("pub struct TeamResponse {"));
+/// assert!(code.contains("    pub id: i64,\n"));
+/// assert!(code.contains("    pub franchise_id: Option<i64>,\n"));
+/// assert!(code.contains("    pub full_name: Option<String>,\n"));
+/// assert!(code.contains("    #[serde(rename = \"pointForOTLossInUse\")]\n"));
+/// assert!(code.contains("    pub point_for_ot_loss_in_use: Option<bool>,\n"));
+/// assert!(code.contains("    pub type_field: Option<String>,\n"));
+/// ```
+pub fn generate(metadata: &GameMetadataResponse) -> String {
+    let mut code = String::from("use serde::{Deserialize, Serialize};\n\n");
+    code.push_str(&generate_struct(&metadata.entity));
+    code
+}
+
+/// Generates the struct and response of an entity, without imports.
+pub fn generate_struct(entity: &Entity) -> String {
+    let name = type_name(&entity.name);
+    let mut code = String::new();
+
+    let _ = writeln!(
+        code,
+        "#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]"
+    );
+    let _ = writeln!(code, "#[serde(rename_all = \"camelCase\")]");
+    let _ = writeln!(code, "pub struct {}Response {{", name);
+    let _ = writeln!(code, "    pub data: Vec<{}>,", name);
+    let _ = writeln!(code, "    pub total: i64,");
+    let _ = writeln!(code, "}}\n");
+
+    let _ = writeln!(
+        code,
+        "#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]"
+    );
+    let _ = writeln!(code, "#[serde(rename_all = \"camelCase\")]");
+    let _ = writeln!(code, "pub struct {} {{", name);
+    for property in &entity.properties {
+        let json_name = json_name(property);
+        let field_name = field_name(&json_name);
+        if camel_case(&field_name) != json_name {
+            let _ = writeln!(code, "    #[serde(rename = \"{}\")]", json_name);
+        }
+        let _ = writeln!(code, "    pub {}: {},", field_name, rust_type(property));
+    }
+    let _ = writeln!(code, "}}");
+    code
+}
+
+/// The Rust type of a property.
+pub fn rust_type(property: &Property) -> String {
+    if property.relationship == Some(true) {
+        return "Option<i64>".to_string();
+    }
+    let rust_type = match FieldType::from_metadata(&property.type_field, property.format.as_deref())
+    {
+        FieldType::String => "String",
+        FieldType::Integer => "i64",
+        FieldType::Number => "f64",
+        FieldType::Boolean => "bool",
+        FieldType::Array => "Vec<serde_json::Value>",
+        FieldType::Object | FieldType::Unknown => "serde_json::Value",
+    };
+    if property.name == "id" {
+        rust_type.to_string()
+    } else {
+        format!("Option<{}>", rust_type)
+    }
+}
+
+/// The name of a property in JSON rows, with `Id` appended to relationships.
+fn json_name(property: &Property) -> String {
+    if property.relationship == Some(true) && !property.name.ends_with("Id") {
+        format!("{}Id", property.name)
+    } else {
+        property.name.clone()
+    }
+}
+
+/// The snake case field name of a camel case JSON name, keeping acronyms together, e.g.
+/// `pointForOTLossInUse` becomes `point_for_ot_loss_in_use`.
+fn field_name(json_name: &str) -> String {
+    let characters = json_name.chars().collect::<Vec<_>>();
+    let mut name = String::new();
+    for (index, character) in characters.iter().enumerate() {
+        if character.is_ascii_uppercase() && index > 0 {
+            let previous = characters[index - 1];
+            let next_is_lower = characters
+                .get(index + 1)
+                .is_some_and(|next| next.is_ascii_lowercase());
+            if !previous.is_ascii_uppercase() || next_is_lower {
+                name.push('_');
+            }
+        }
+        if character.is_ascii_alphanumeric() {
+            name.push(character.to_ascii_lowercase());
+        } else if !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    let name = name.trim_matches('_').to_string();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("field_{}", name)
+    } else if KEYWORDS.contains(&name.as_str()) {
+        format!("{}_field", name)
+    } else {
+        name
+    }
+}
+
+/// The camel case name serde gives a snake case field.
+fn camel_case(field_name: &str) -> String {
+    let mut name = String::new();
+    let mut upper = false;
+    for character in field_name.chars() {
+        if character == '_' {
+            upper = true;
+        } else if upper {
+            name.push(character.to_ascii_uppercase());
+            upper = false;
+        } else {
+            name.push(character);
+        }
+    }
+    name
+}
+
+/// The Pascal case type name of an entity, e.g. `Country` or `SkaterSummary`.
+fn type_name(entity_name: &str) -> String {
+    let mut name = String::new();
+    let mut upper = true;
+    for character in entity_name.chars() {
+        if character.is_ascii_alphanumeric() {
+            if upper {
+                name.push(character.to_ascii_uppercase());
+            } else {
+                name.push(character);
+            }
+            upper = false;
+        } else {
+            upper = true;
+        }
+    }
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Entity{}", name)
+    } else {
+        name
+    }
+}
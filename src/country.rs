@@ -1,4 +1,8 @@
-use crate::{http::get, Client};
+use crate::{
+    http::get,
+    names::{self, NameMatch},
+    Client,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -49,7 +53,7 @@ impl Client {
     /// ```
     pub async fn get_countries(&self) -> Result<Vec<Country>, u16> {
         let url = format!("{}/{}/country", self.stats_base_url, self.language);
-        let result = get::<CountriesResponse>(url).await;
+        let result = get::<CountriesResponse>(&self.transport, url).await;
         match result {
             Ok(response) => Ok(response.data),
             Err(status_code) => Err(status_code),
@@ -89,6 +93,8 @@ impl Client {
 
     /// Get a country by a `country_name`.
     ///
+    /// Names are compared without diacritics, case or punctuation.
+    ///
     /// # Errors
     /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
     ///
@@ -112,15 +118,48 @@ impl Client {
         country_name: &str,
     ) -> Result<Option<Country>, u16> {
         let result = self.get_countries().await?;
-        let name = country_name.to_uppercase();
         Ok(result
             .into_iter()
-            .filter(|country| country.country_name.to_uppercase() == name)
+            .filter(|country| names::same_name(&country.country_name, country_name))
             .collect::<Vec<_>>()
             .first()
             .cloned())
     }
 
+    /// Search countries by name or nationality, best match first.
+    ///
+    /// Names are compared without diacritics, case or punctuation, and tolerate typos.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.search_countries("Swizerland").await?;
+    ///
+    /// for candidate in response {
+    ///     println!("{} ({})", candidate.item.country_name, candidate.score);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn search_countries(&self, query: &str) -> Result<Vec<NameMatch<Country>>, u16> {
+        let result = self.get_countries().await?;
+        Ok(names::rank(result, query, |country| {
+            vec![
+                country.country_name.as_str(),
+                country.nationality_name.as_str(),
+            ]
+        }))
+    }
+
     /// Get a country by a `country_ioc_code`.
     ///
     /// # Errors
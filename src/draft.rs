@@ -0,0 +1,245 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{country::Country, http::get, ids::TeamId, localized::LocalizedString, Client};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftResponse {
+    pub data: Vec<Draft>,
+    pub total: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Draft {
+    pub id: i64,
+    pub draft_year: i64,
+    pub rounds: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftPicksResponse {
+    pub draft_year: i64,
+    pub draft_years: Vec<i64>,
+    pub selectable_rounds: Vec<i64>,
+    pub state: Option<String>,
+    pub picks: Vec<DraftPick>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftPick {
+    pub round: i64,
+    pub pick_in_round: i64,
+    pub overall_pick: i64,
+    pub team_id: TeamId,
+    pub team_abbrev: String,
+    pub team_name: LocalizedString,
+    pub team_common_name: Option<LocalizedString>,
+    /// The teams that have owned the pick, e.g. `NJD-CGY`.
+    pub team_pick_history: Option<String>,
+    pub first_name: LocalizedString,
+    pub last_name: LocalizedString,
+    pub position_code: Option<String>,
+    /// The three letter country code, e.g. `CAN`.
+    pub country_code: Option<String>,
+    /// Height, in inches.
+    pub height: Option<i64>,
+    /// Weight, in pounds.
+    pub weight: Option<i64>,
+    pub amateur_league: Option<String>,
+    pub amateur_club_name: Option<String>,
+}
+
+impl DraftPick {
+    /// The full name of the drafted player.
+    pub fn player_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+
+    /// The country of the drafted player, found in a list of countries.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{Country, DraftPick};
+    ///
+    /// let countries = [Country {
+    ///     id: "CAN".to_string(),
+    ///     country3code: "CAN".to_string(),
+    ///     country_name: "Canada".to_string(),
+    ///     ..Default::default()
+    /// }];
+    /// let pick = DraftPick {
+    ///     country_code: Some("CAN".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(pick.country(&countries).unwrap().country_name, "Canada");
+    /// ```
+    pub fn country(&self, countries: &[Country]) -> Option<Country> {
+        let code = self.country_code.as_ref()?.to_uppercase();
+        countries
+            .iter()
+            .find(|country| country.country3code.to_uppercase() == code)
+            .cloned()
+    }
+}
+
+/// A category of the NHL Central Scouting draft rankings.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DraftRankingCategory {
+    #[default]
+    NorthAmericanSkaters,
+    InternationalSkaters,
+    NorthAmericanGoalies,
+    InternationalGoalies,
+}
+
+impl DraftRankingCategory {
+    /// The id of the category used by the NHL API.
+    pub fn id(&self) -> i64 {
+        match self {
+            Self::NorthAmericanSkaters => 1,
+            Self::InternationalSkaters => 2,
+            Self::NorthAmericanGoalies => 3,
+            Self::InternationalGoalies => 4,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftRankingsResponse {
+    pub draft_year: i64,
+    pub category_id: i64,
+    pub category_key: String,
+    pub draft_years: Vec<i64>,
+    pub rankings: Vec<DraftRanking>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftRanking {
+    pub first_name: String,
+    pub last_name: String,
+    pub position_code: Option<String>,
+    pub shoots_catches: Option<String>,
+    pub height_in_inches: Option<i64>,
+    pub weight_in_pounds: Option<i64>,
+    pub last_amateur_club: Option<String>,
+    pub last_amateur_league: Option<String>,
+    /// `YYYY-MM-DD`.
+    pub birth_date: Option<String>,
+    pub birth_city: Option<String>,
+    pub birth_state_province: Option<String>,
+    /// The three letter country code, e.g. `CAN`.
+    pub birth_country: Option<String>,
+    pub midterm_rank: Option<i64>,
+    pub final_rank: Option<i64>,
+}
+
+impl Client {
+    /// Get a list of drafts.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_drafts().await?;
+    ///
+    /// println!("Got {} drafts", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_drafts(&self) -> Result<Vec<Draft>, u16> {
+        let url = format!("{}/{}/draft", self.stats_base_url, self.language);
+        let result = get::<DraftResponse>(&self.transport, url).await;
+        match result {
+            Ok(response) => Ok(response.data),
+            Err(status_code) => Err(status_code),
+        }
+    }
+
+    /// Get the picks of a draft `year`, for a single `round` or every round when `None`.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_draft_picks(2023, Some(1)).await?;
+    ///
+    /// for pick in response.picks {
+    ///     println!("{}. {} ({})", pick.overall_pick, pick.player_name(), pick.team_abbrev);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_draft_picks(
+        &self,
+        year: i64,
+        round: Option<i64>,
+    ) -> Result<DraftPicksResponse, u16> {
+        let round = match round {
+            Some(round) => round.to_string(),
+            None => "all".to_string(),
+        };
+        let url = format!("{}/v1/draft/picks/{}/{}", self.base_url, year, round);
+        get::<DraftPicksResponse>(&self.transport, url).await
+    }
+
+    /// Get the NHL Central Scouting rankings of draft prospects for a draft `year`.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, DraftRankingCategory};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client
+    ///     .get_draft_rankings(2024, DraftRankingCategory::InternationalSkaters)
+    ///     .await?;
+    ///
+    /// for ranking in response.rankings.iter().take(10) {
+    ///     println!("{:?}. {} {}", ranking.final_rank, ranking.first_name, ranking.last_name);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_draft_rankings(
+        &self,
+        year: i64,
+        category: DraftRankingCategory,
+    ) -> Result<DraftRankingsResponse, u16> {
+        let url = format!(
+            "{}/v1/draft/rankings/{}/{}",
+            self.base_url,
+            year,
+            category.id()
+        );
+        get::<DraftRankingsResponse>(&self.transport, url).await
+    }
+}
@@ -0,0 +1,325 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::GameType,
+    http::get,
+    ids::{SeasonId, TeamId},
+    localized::LocalizedString,
+    Client,
+};
+
+/// The games NHL Edge stats are computed over.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EdgeSelection {
+    /// The current season and game type.
+    #[default]
+    Now,
+    Season(SeasonId, GameType),
+}
+
+impl EdgeSelection {
+    /// The regular season of a season.
+    pub fn regular_season(season: SeasonId) -> Self {
+        Self::Season(season, GameType::RegularSeason)
+    }
+
+    /// The playoffs of a season.
+    pub fn playoffs(season: SeasonId) -> Self {
+        Self::Season(season, GameType::Playoffs)
+    }
+
+    /// The path of the selection, e.g. `now` or `20232024/2`.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{EdgeSelection, SeasonId};
+    ///
+    /// let season = SeasonId::new(20232024).unwrap();
+    /// assert_eq!(EdgeSelection::playoffs(season).path(), "20232024/3");
+    /// assert_eq!(EdgeSelection::Now.path(), "now");
+    /// ```
+    pub fn path(&self) -> String {
+        match self {
+            Self::Now => "now".to_string(),
+            Self::Season(season, game_type) => format!("{}/{}", season.id(), game_type.id()),
+        }
+    }
+}
+
+/// A season with NHL Edge stats, and its game types with stats.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeSeason {
+    pub id: SeasonId,
+    #[serde(default)]
+    pub game_types: Vec<GameType>,
+}
+
+/// The imperial and metric values of a measurement, e.g. a speed in mph and km/h.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeValue {
+    pub imperial: Option<f64>,
+    pub metric: Option<f64>,
+}
+
+/// A measurement of a player or team, ranked against the league.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeMeasurement {
+    pub imperial: Option<f64>,
+    pub metric: Option<f64>,
+    /// The percentile against the league, from `0.0` to `1.0`.
+    pub percentile: Option<f64>,
+    pub league_avg: Option<EdgeValue>,
+}
+
+/// A count of a player or team, e.g. skating bursts, ranked against the league.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeCount {
+    pub value: Option<f64>,
+    /// The percentile against the league, from `0.0` to `1.0`.
+    pub percentile: Option<f64>,
+    pub league_avg: Option<EdgeCountAverage>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeCountAverage {
+    pub value: Option<f64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkatingSpeed {
+    pub speed_max: Option<EdgeMeasurement>,
+    /// Bursts over 20 mph.
+    pub bursts_over20: Option<EdgeCount>,
+    /// Bursts over 22 mph.
+    pub bursts_over22: Option<EdgeCount>,
+}
+
+/// The share of time spent in each zone, at even strength.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneTime {
+    pub offensive_zone_pctg: Option<f64>,
+    pub offensive_zone_percentile: Option<f64>,
+    pub offensive_zone_league_avg: Option<f64>,
+    pub neutral_zone_pctg: Option<f64>,
+    pub neutral_zone_percentile: Option<f64>,
+    pub neutral_zone_league_avg: Option<f64>,
+    pub defensive_zone_pctg: Option<f64>,
+    pub defensive_zone_percentile: Option<f64>,
+    pub defensive_zone_league_avg: Option<f64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgePlayer {
+    pub id: i64,
+    pub first_name: LocalizedString,
+    pub last_name: LocalizedString,
+    pub position: Option<String>,
+    pub sweater_number: Option<i64>,
+    pub team: Option<EdgeTeam>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeTeam {
+    pub id: TeamId,
+    pub common_name: Option<LocalizedString>,
+    pub place_name_with_preposition: Option<LocalizedString>,
+    pub abbrev: Option<String>,
+}
+
+/// The NHL Edge page of a skater.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkaterEdgeDetail {
+    pub player: Option<EdgePlayer>,
+    #[serde(default)]
+    pub seasons_with_edge_stats: Vec<EdgeSeason>,
+    pub top_shot_speed: Option<EdgeMeasurement>,
+    pub skating_speed: Option<SkatingSpeed>,
+    pub total_distance_skated: Option<EdgeMeasurement>,
+    /// The longest distance skated in a game.
+    pub distance_max_game: Option<EdgeMeasurement>,
+    pub zone_time_details: Option<ZoneTime>,
+}
+
+/// The save percentage of a goalie on shots from an area of the ice, e.g. `high`, `mid` or
+/// `long`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveLocation {
+    pub location_code: String,
+    pub save_pctg: Option<f64>,
+    pub save_pctg_percentile: Option<f64>,
+    pub league_avg: Option<f64>,
+    pub saves: Option<i64>,
+    pub goals_against: Option<i64>,
+}
+
+/// The NHL Edge page of a goalie.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalieEdgeDetail {
+    pub player: Option<EdgePlayer>,
+    #[serde(default)]
+    pub seasons_with_edge_stats: Vec<EdgeSeason>,
+    #[serde(default)]
+    pub shot_location_summary: Vec<SaveLocation>,
+    pub games_above900: Option<EdgeCount>,
+    pub goals_against_avg: Option<EdgeCount>,
+    pub save_pctg: Option<EdgeCount>,
+}
+
+impl GoalieEdgeDetail {
+    /// The save percentage on shots from a location, e.g. `high`.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::GoalieEdgeDetail;
+    ///
+    /// let detail: GoalieEdgeDetail = serde_json::from_str(
+    ///     r#"{"shotLocationSummary": [{"locationCode": "high", "savePctg": 0.82}]}"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(detail.save_percentage_at("HIGH"), Some(0.82));
+    /// assert_eq!(detail.save_percentage_at("long"), None);
+    /// assert!(detail.seasons_with_edge_stats.is_empty());
+    /// ```
+    pub fn save_percentage_at(&self, location_code: &str) -> Option<f64> {
+        self.shot_location_summary
+            .iter()
+            .find(|location| location.location_code.eq_ignore_ascii_case(location_code))?
+            .save_pctg
+    }
+}
+
+/// The NHL Edge summary of a team.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamEdgeDetail {
+    pub team: Option<EdgeTeam>,
+    #[serde(default)]
+    pub seasons_with_edge_stats: Vec<EdgeSeason>,
+    pub shot_speed: Option<EdgeMeasurement>,
+    pub skating_speed: Option<SkatingSpeed>,
+    pub distance_skated: Option<EdgeMeasurement>,
+    pub zone_time_details: Option<ZoneTime>,
+}
+
+impl Client {
+    /// Get the NHL Edge page of a skater: shot and skating speeds, skating bursts, distance
+    /// skated and zone time.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, EdgeSelection, SeasonId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let season = SeasonId::new(20232024).unwrap();
+    /// let response = client
+    ///     .get_skater_edge(8478402, EdgeSelection::regular_season(season))
+    ///     .await?;
+    ///
+    /// println!("Top shot speed: {:?}", response.top_shot_speed);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_skater_edge(
+        &self,
+        player_id: i64,
+        selection: EdgeSelection,
+    ) -> Result<SkaterEdgeDetail, u16> {
+        let url = format!(
+            "{}/v1/edge/skater-detail/{}/{}",
+            self.base_url,
+            player_id,
+            selection.path()
+        );
+        get::<SkaterEdgeDetail>(&self.transport, url).await
+    }
+
+    /// Get the NHL Edge page of a goalie, including the save percentage by shot location.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, EdgeSelection};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_goalie_edge(8476945, EdgeSelection::Now).await?;
+    ///
+    /// println!("High danger save %: {:?}", response.save_percentage_at("high"));
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_goalie_edge(
+        &self,
+        player_id: i64,
+        selection: EdgeSelection,
+    ) -> Result<GoalieEdgeDetail, u16> {
+        let url = format!(
+            "{}/v1/edge/goalie-detail/{}/{}",
+            self.base_url,
+            player_id,
+            selection.path()
+        );
+        get::<GoalieEdgeDetail>(&self.transport, url).await
+    }
+
+    /// Get the NHL Edge summary of a team.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, EdgeSelection, TeamId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let team = TeamId::new(6).unwrap();
+    /// let response = client.get_team_edge(team, EdgeSelection::Now).await?;
+    ///
+    /// println!("Zone time: {:?}", response.zone_time_details);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_team_edge(
+        &self,
+        team_id: TeamId,
+        selection: EdgeSelection,
+    ) -> Result<TeamEdgeDetail, u16> {
+        let url = format!(
+            "{}/v1/edge/team-detail/{}/{}",
+            self.base_url,
+            team_id,
+            selection.path()
+        );
+        get::<TeamEdgeDetail>(&self.transport, url).await
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{http::get, Client};
+use crate::{
+    http::get,
+    names::{self, NameMatch},
+    Client,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -40,7 +44,7 @@ impl Client {
     /// ```
     pub async fn get_franchises(&self) -> Result<Vec<Franchise>, u16> {
         let url = format!("{}/{}/franchise", self.stats_base_url, self.language);
-        let result = get::<FranchiseResponse>(url).await;
+        let result = get::<FranchiseResponse>(&self.transport, url).await;
         match result {
             Ok(response) => Ok(response.data),
             Err(status_code) => Err(status_code),
@@ -75,6 +79,8 @@ impl Client {
 
     /// Get a franchise by a `full_name`.
     ///
+    /// Names are compared without diacritics, case or punctuation.
+    ///
     /// # Errors
     /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
     ///
@@ -94,10 +100,9 @@ impl Client {
         full_name: &str,
     ) -> Result<Option<Franchise>, u16> {
         let result = self.get_franchises().await?;
-        let name = full_name.to_uppercase();
         Ok(result
             .into_iter()
-            .filter(|franchise| franchise.full_name.to_uppercase() == name)
+            .filter(|franchise| names::same_name(&franchise.full_name, full_name))
             .collect::<Vec<_>>()
             .first()
             .cloned())
@@ -105,6 +110,8 @@ impl Client {
 
     /// Get a franchise by a `team_common_name`.
     ///
+    /// Names are compared without diacritics, case or punctuation.
+    ///
     /// # Errors
     /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
     ///
@@ -124,10 +131,9 @@ impl Client {
         team_common_name: &str,
     ) -> Result<Option<Franchise>, u16> {
         let result = self.get_franchises().await?;
-        let name = team_common_name.to_uppercase();
         Ok(result
             .into_iter()
-            .filter(|franchise| franchise.team_common_name.to_uppercase() == name)
+            .filter(|franchise| names::same_name(&franchise.team_common_name, team_common_name))
             .collect::<Vec<_>>()
             .first()
             .cloned())
@@ -135,6 +141,8 @@ impl Client {
 
     /// Get a franchise by a `team_place_name`.
     ///
+    /// Names are compared without diacritics, case or punctuation.
+    ///
     /// # Errors
     /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
     ///
@@ -154,12 +162,46 @@ impl Client {
         team_place_name: &str,
     ) -> Result<Option<Franchise>, u16> {
         let result = self.get_franchises().await?;
-        let name = team_place_name.to_uppercase();
         Ok(result
             .into_iter()
-            .filter(|franchise| franchise.team_place_name.to_uppercase() == name)
+            .filter(|franchise| names::same_name(&franchise.team_place_name, team_place_name))
             .collect::<Vec<_>>()
             .first()
             .cloned())
     }
+
+    /// Search franchises by full name, common name or place name, best match first.
+    ///
+    /// Names are compared without diacritics, case or punctuation, and tolerate typos.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.search_franchises("Montreal Canadians").await?;
+    ///
+    /// for candidate in response {
+    ///     println!("{} ({})", candidate.item.full_name, candidate.score);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn search_franchises(&self, query: &str) -> Result<Vec<NameMatch<Franchise>>, u16> {
+        let result = self.get_franchises().await?;
+        Ok(names::rank(result, query, |franchise| {
+            vec![
+                franchise.full_name.as_str(),
+                franchise.team_common_name.as_str(),
+                franchise.team_place_name.as_str(),
+            ]
+        }))
+    }
 }
@@ -0,0 +1,419 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    franchise::Franchise,
+    game::{Game, GameType},
+    ids::{SeasonId, TeamId},
+    records::FranchiseSeasonResult,
+    team::Team,
+    Client,
+};
+
+/// Team common names that are more than one word, used to split a team's full name into its city
+/// and common name.
+const MULTI_WORD_COMMON_NAMES: [&str; 8] = [
+    "Blue Jackets",
+    "Golden Knights",
+    "Golden Seals",
+    "Hockey Club",
+    "Maple Leafs",
+    "North Stars",
+    "Red Wings",
+    "St. Patricks",
+];
+
+/// A name a franchise played under, e.g. the Quebec Nordiques from 1979-80 to 1994-95.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FranchiseEra {
+    pub team_id: TeamId,
+    pub full_name: String,
+    pub city: String,
+    pub common_name: String,
+    pub tri_code: String,
+    /// The first season the team played a regular season or playoff game, if it has played any.
+    pub first_season: Option<SeasonId>,
+    /// The last season the team played a regular season or playoff game, if it has played any.
+    pub last_season: Option<SeasonId>,
+}
+
+impl FranchiseEra {
+    /// Whether the team played in a season.
+    pub fn includes(&self, season: SeasonId) -> bool {
+        match (self.first_season, self.last_season) {
+            (Some(first), Some(last)) => (first..=last).contains(&season),
+            _ => false,
+        }
+    }
+}
+
+/// The chronological timeline of the teams a franchise has played as.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{Franchise, FranchiseHistory, Game, GameId, SeasonId, Team, TeamId};
+///
+/// let franchise = Franchise {
+///     id: 27,
+///     full_name: "Colorado Avalanche".to_string(),
+///     team_common_name: "Avalanche".to_string(),
+///     team_place_name: "Colorado".to_string(),
+/// };
+/// let nordiques = Team {
+///     id: TeamId::new(32).unwrap(),
+///     franchise_id: Some(27),
+///     full_name: "Quebec Nordiques".to_string(),
+///     tri_code: "QUE".to_string(),
+///     ..Default::default()
+/// };
+/// let avalanche = Team {
+///     id: TeamId::new(21).unwrap(),
+///     franchise_id: Some(27),
+///     full_name: "Colorado Avalanche".to_string(),
+///     tri_code: "COL".to_string(),
+///     ..Default::default()
+/// };
+/// let games = [
+///     Game {
+///         id: GameId::new(1979020001).unwrap(),
+///         season: SeasonId::new(19791980).unwrap(),
+///         home_team_id: nordiques.id,
+///         ..Default::default()
+///     },
+///     Game {
+///         id: GameId::new(1995020001).unwrap(),
+///         season: SeasonId::new(19951996).unwrap(),
+///         visiting_team_id: avalanche.id,
+///         ..Default::default()
+///     },
+/// ];
+///
+/// let history = FranchiseHistory::new(franchise, &[avalanche, nordiques], &games, &[]);
+///
+/// assert_eq!(history.eras[0].city, "Quebec");
+/// assert_eq!(
+///     history.name_in_season(SeasonId::new(19791980).unwrap()),
+///     Some("Quebec Nordiques")
+/// );
+/// assert_eq!(history.current().unwrap().tri_code, "COL");
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FranchiseHistory {
+    pub franchise: Franchise,
+    /// Eras ordered by their first season. Teams that have not played yet come last.
+    pub eras: Vec<FranchiseEra>,
+}
+
+impl FranchiseHistory {
+    /// Builds the history of a franchise from every team and the games they played.
+    ///
+    /// Teams belong to the franchise through `Team.franchise_id`. Season ranges come from regular
+    /// season and playoff games, so `games` should cover every season of the franchise.
+    ///
+    /// A team renamed without a new team id, e.g. the Mighty Ducks of Anaheim becoming the
+    /// Anaheim Ducks, is split into an era per name using the `team_name` of each season in
+    /// `season_results`, e.g. from [`Client::get_franchise_season_results`]. Without season
+    /// results, such a team is a single era under its current name.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{Franchise, FranchiseHistory, Game, GameId, SeasonId, Team, TeamId};
+    ///
+    /// let franchise = Franchise {
+    ///     id: 15,
+    ///     full_name: "Dallas Stars".to_string(),
+    ///     team_common_name: "Stars".to_string(),
+    ///     team_place_name: "Dallas".to_string(),
+    /// };
+    /// let north_stars = Team {
+    ///     id: TeamId::new(31).unwrap(),
+    ///     franchise_id: Some(15),
+    ///     full_name: "Minnesota North Stars".to_string(),
+    ///     tri_code: "MNS".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let stars = Team {
+    ///     id: TeamId::new(25).unwrap(),
+    ///     franchise_id: Some(15),
+    ///     full_name: "Dallas Stars".to_string(),
+    ///     tri_code: "DAL".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let games = [
+    ///     Game {
+    ///         id: GameId::new(1992020001).unwrap(),
+    ///         season: SeasonId::new(19921993).unwrap(),
+    ///         home_team_id: north_stars.id,
+    ///         ..Default::default()
+    ///     },
+    ///     Game {
+    ///         id: GameId::new(1993020001).unwrap(),
+    ///         season: SeasonId::new(19931994).unwrap(),
+    ///         home_team_id: stars.id,
+    ///         ..Default::default()
+    ///     },
+    /// ];
+    ///
+    /// let history = FranchiseHistory::new(franchise, &[stars, north_stars], &games, &[]);
+    ///
+    /// assert_eq!(history.eras[0].city, "Minnesota");
+    /// assert_eq!(history.eras[0].common_name, "North Stars");
+    /// assert_eq!(history.eras[0].last_season, SeasonId::new(19921993).ok());
+    /// assert_eq!(history.eras[1].city, "Dallas");
+    /// assert_eq!(history.eras[1].common_name, "Stars");
+    /// assert_eq!(history.eras[1].first_season, SeasonId::new(19931994).ok());
+    /// ```
+    ///
+    /// A rename keeping the team id:
+    /// ```
+    /// use nhl_rs::{
+    ///     Franchise, FranchiseHistory, FranchiseSeasonResult, Game, GameId, SeasonId, Team, TeamId,
+    /// };
+    ///
+    /// let franchise = Franchise {
+    ///     id: 32,
+    ///     full_name: "Anaheim Ducks".to_string(),
+    ///     team_common_name: "Ducks".to_string(),
+    ///     team_place_name: "Anaheim".to_string(),
+    /// };
+    /// let ducks = Team {
+    ///     id: TeamId::new(24).unwrap(),
+    ///     franchise_id: Some(32),
+    ///     full_name: "Anaheim Ducks".to_string(),
+    ///     tri_code: "ANA".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let games = [
+    ///     Game {
+    ///         id: GameId::new(1993020001).unwrap(),
+    ///         season: SeasonId::new(19931994).unwrap(),
+    ///         home_team_id: ducks.id,
+    ///         ..Default::default()
+    ///     },
+    ///     Game {
+    ///         id: GameId::new(2007020001).unwrap(),
+    ///         season: SeasonId::new(20072008).unwrap(),
+    ///         visiting_team_id: ducks.id,
+    ///         ..Default::default()
+    ///     },
+    /// ];
+    /// let season_results = [
+    ///     ("Mighty Ducks of Anaheim", 19931994),
+    ///     ("Mighty Ducks of Anaheim", 20052006),
+    ///     ("Anaheim Ducks", 20062007),
+    /// ]
+    /// .map(|(team_name, season)| FranchiseSeasonResult {
+    ///     franchise_id: 32,
+    ///     season_id: SeasonId::new(season).unwrap(),
+    ///     team_id: Some(ducks.id),
+    ///     team_name: Some(team_name.to_string()),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// let history = FranchiseHistory::new(franchise, &[ducks], &games, &season_results);
+    ///
+    /// assert_eq!(history.eras.len(), 2);
+    /// assert_eq!(history.eras[0].city, "Anaheim");
+    /// assert_eq!(history.eras[0].common_name, "Mighty Ducks");
+    /// assert_eq!(history.eras[0].last_season, SeasonId::new(20052006).ok());
+    /// assert_eq!(history.eras[1].first_season, SeasonId::new(20062007).ok());
+    /// assert_eq!(history.eras[1].last_season, SeasonId::new(20072008).ok());
+    /// assert_eq!(
+    ///     history.name_in_season(SeasonId::new(19931994).unwrap()),
+    ///     Some("Mighty Ducks of Anaheim")
+    /// );
+    /// assert_eq!(
+    ///     history.name_in_season(SeasonId::new(20072008).unwrap()),
+    ///     Some("Anaheim Ducks")
+    /// );
+    /// ```
+    pub fn new(
+        franchise: Franchise,
+        teams: &[Team],
+        games: &[Game],
+        season_results: &[FranchiseSeasonResult],
+    ) -> Self {
+        let mut seasons: HashMap<TeamId, (SeasonId, SeasonId)> = HashMap::new();
+        for game in games {
+            if !matches!(game.game_type, GameType::RegularSeason | GameType::Playoffs) {
+                continue;
+            }
+            for team_id in [game.home_team_id, game.visiting_team_id] {
+                seasons
+                    .entry(team_id)
+                    .and_modify(|(first, last)| {
+                        *first = (*first).min(game.season);
+                        *last = (*last).max(game.season);
+                    })
+                    .or_insert((game.season, game.season));
+            }
+        }
+
+        let mut eras = franchise
+            .teams(teams)
+            .into_iter()
+            .flat_map(|team| {
+                let range = seasons.get(&team.id);
+                let era = FranchiseEra {
+                    team_id: team.id,
+                    city: String::new(),
+                    common_name: String::new(),
+                    full_name: team.full_name,
+                    tri_code: team.tri_code,
+                    first_season: range.map(|(first, _)| *first),
+                    last_season: range.map(|(_, last)| *last),
+                };
+                split_by_season_names(era, season_results)
+            })
+            .map(|mut era| {
+                (era.city, era.common_name) = split_full_name(&franchise, &era.full_name);
+                era
+            })
+            .collect::<Vec<_>>();
+        eras.sort_by_key(|era| (era.first_season.is_none(), era.first_season, era.team_id));
+
+        Self { franchise, eras }
+    }
+
+    /// The team the franchise played as in a season.
+    pub fn team_in_season(&self, season: SeasonId) -> Option<&FranchiseEra> {
+        self.eras.iter().find(|era| era.includes(season))
+    }
+
+    /// The full name the franchise played under in a season.
+    pub fn name_in_season(&self, season: SeasonId) -> Option<&str> {
+        self.team_in_season(season)
+            .map(|era| era.full_name.as_str())
+    }
+
+    /// The most recent team of the franchise.
+    pub fn current(&self) -> Option<&FranchiseEra> {
+        self.eras.last()
+    }
+}
+
+/// Splits the era of a team into an era per name it played under, from the team names of its
+/// season results. Seasons without a result keep the name of the closest earlier season.
+fn split_by_season_names(
+    era: FranchiseEra,
+    season_results: &[FranchiseSeasonResult],
+) -> Vec<FranchiseEra> {
+    let names = season_results
+        .iter()
+        .filter(|result| result.team_id == Some(era.team_id))
+        .filter_map(|result| Some((result.season_id, result.team_name.clone()?)))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut eras: Vec<FranchiseEra> = vec![];
+    for (season, name) in names {
+        match eras.last_mut() {
+            Some(last) if last.full_name == name => last.last_season = Some(season),
+            _ => eras.push(FranchiseEra {
+                full_name: name,
+                first_season: Some(season),
+                last_season: Some(season),
+                ..era.clone()
+            }),
+        }
+    }
+    if eras.is_empty() {
+        return vec![era];
+    }
+
+    // Stretch the names over the seasons the team played without a result.
+    for index in 1..eras.len() {
+        let next_first = eras[index].first_season;
+        if let (Some(first), Some(next_first)) = (eras[index - 1].first_season, next_first) {
+            eras[index - 1].last_season = SeasonId::from_start_year(next_first.start_year() - 1)
+                .ok()
+                .filter(|last| *last >= first);
+        }
+    }
+    if let (Some(first), Some(played)) = (eras[0].first_season, era.first_season) {
+        eras[0].first_season = Some(first.min(played));
+    }
+    if let Some(last) = eras.last_mut() {
+        last.last_season = last.last_season.max(era.last_season);
+    }
+    eras
+}
+
+/// Splits a team's full name into its city and common name, e.g. `St. Louis` and `Blues`, or
+/// `Anaheim` and `Mighty Ducks` for the `Mighty Ducks of Anaheim`.
+fn split_full_name(franchise: &Franchise, full_name: &str) -> (String, String) {
+    if full_name == franchise.full_name {
+        return (
+            franchise.team_place_name.clone(),
+            franchise.team_common_name.clone(),
+        );
+    }
+    if let Some((common_name, city)) = full_name.split_once(" of ") {
+        return (city.to_string(), common_name.to_string());
+    }
+
+    let common_name = MULTI_WORD_COMMON_NAMES
+        .iter()
+        .find(|name| full_name.ends_with(&format!(" {}", name)))
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| {
+            full_name
+                .rsplit_once(' ')
+                .map_or(full_name, |(_, name)| name)
+                .to_string()
+        });
+    let city = full_name
+        .strip_suffix(&common_name)
+        .unwrap_or_default()
+        .trim_end()
+        .to_string();
+    (city, common_name)
+}
+
+impl Client {
+    /// Get the history of a franchise by an `id`.
+    ///
+    /// This fetches every franchise, team and game, and the franchise's season results, so prefer
+    /// [`FranchiseHistory::new`] when those are already available.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, SeasonId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let history = client.get_franchise_history(27).await?.unwrap();
+    ///
+    /// for era in &history.eras {
+    ///     println!("{} ({})", era.full_name, era.tri_code);
+    /// }
+    /// println!(
+    ///     "In 1993-94 the franchise played as the {:?}",
+    ///     history.name_in_season(SeasonId::new(19931994).unwrap())
+    /// );
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_franchise_history(&self, id: i64) -> Result<Option<FranchiseHistory>, u16> {
+        let Some(franchise) = self.get_franchise_by_id(id).await? else {
+            return Ok(None);
+        };
+        let teams = self.get_teams().await?;
+        let games = self.get_games().await?;
+        let season_results = self.get_franchise_season_results(id, None).await?;
+        Ok(Some(FranchiseHistory::new(
+            franchise,
+            &teams,
+            &games,
+            &season_results,
+        )))
+    }
+}
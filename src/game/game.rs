@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    game::state::{GameScheduleState, GameState, GameType},
+    ids::{GameId, SeasonId, TeamId},
+};
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GameResponse {
@@ -10,17 +15,17 @@ pub struct GameResponse {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Game {
-    pub id: i64,
+    pub id: GameId,
     pub eastern_start_time: String,
     pub game_date: String,
     pub game_number: i64,
-    pub game_schedule_state_id: i64,
-    pub game_state_id: i64,
-    pub game_type: i64,
+    pub game_schedule_state_id: GameScheduleState,
+    pub game_state_id: GameState,
+    pub game_type: GameType,
     pub home_score: i64,
-    pub home_team_id: i64,
+    pub home_team_id: TeamId,
     pub period: Option<i64>,
-    pub season: i64,
+    pub season: SeasonId,
     pub visiting_score: i64,
-    pub visiting_team_id: i64,
+    pub visiting_team_id: TeamId,
 }
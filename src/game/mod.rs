@@ -1,11 +1,22 @@
+#[allow(clippy::module_inception)]
 pub mod game;
 pub mod metadata;
+pub mod play_by_play;
+pub mod state;
+#[cfg(feature = "chrono")]
+pub mod time;
 
-use crate::{http::get, Client};
+use crate::{
+    http::get,
+    ids::{GameId, SeasonId, TeamId},
+    Client,
+};
 
 pub use crate::game::{
     game::{Game, GameResponse},
-    metadata::GameMetadataResponse,
+    metadata::{Entity, GameMetadataResponse, Property},
+    play_by_play::{PeriodDescriptor, Play, PlayByPlayResponse, PlayByPlayTeam, PlayDetails},
+    state::{GameScheduleState, GameState, GameType},
 };
 
 impl Client {
@@ -28,7 +39,7 @@ impl Client {
     /// ```
     pub async fn get_game_metadata(&self) -> Result<GameMetadataResponse, u16> {
         let url = format!("{}/{}/game/meta", self.stats_base_url, self.language);
-        get::<GameMetadataResponse>(url).await
+        get::<GameMetadataResponse>(&self.transport, url).await
     }
 
     /// Get most games in existance (both played and scheduled).
@@ -53,7 +64,7 @@ impl Client {
     /// ```
     pub async fn get_games(&self) -> Result<Vec<Game>, u16> {
         let url = format!("{}/{}/game", self.stats_base_url, self.language);
-        let result = get::<GameResponse>(url).await;
+        let result = get::<GameResponse>(&self.transport, url).await;
         match result {
             Ok(response) => Ok(response.data),
             Err(status_code) => Err(status_code),
@@ -64,20 +75,21 @@ impl Client {
     ///
     /// # Example
     /// ```no_run
-    /// use nhl_rs::ClientBuilder;
+    /// use nhl_rs::{ClientBuilder, GameId};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), u16> {
     /// let client = ClientBuilder::new().build();
     ///
-    /// let response = client.get_game_by_id(2017020120).await?;
+    /// let id = GameId::new(2017020120).unwrap();
+    /// let response = client.get_game_by_id(id).await?;
     ///
     /// println!("Game with id 2017020120: {:?}", response);
     ///
     /// Ok(())
     /// # }
     /// ```
-    pub async fn get_game_by_id(&self, id: i64) -> Result<Option<Game>, u16> {
+    pub async fn get_game_by_id(&self, id: GameId) -> Result<Option<Game>, u16> {
         let result = self.get_games().await?;
         Ok(result
             .into_iter()
@@ -91,20 +103,21 @@ impl Client {
     ///
     /// # Example
     /// ```no_run
-    /// use nhl_rs::ClientBuilder;
+    /// use nhl_rs::{ClientBuilder, TeamId};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), u16> {
     /// let client = ClientBuilder::new().build();
     ///
-    /// let response = client.get_games_for_team_by_team_id(12).await?;
+    /// let id = TeamId::new(12).unwrap();
+    /// let response = client.get_games_for_team_by_team_id(id).await?;
     ///
     /// println!("Team with id of 12 has: {} games", response.len());
     ///
     /// Ok(())
     /// # }
     /// ```
-    pub async fn get_games_for_team_by_team_id(&self, id: i64) -> Result<Vec<Game>, u16> {
+    pub async fn get_games_for_team_by_team_id(&self, id: TeamId) -> Result<Vec<Game>, u16> {
         let result = self.get_games().await?;
         Ok(result
             .into_iter()
@@ -116,24 +129,163 @@ impl Client {
     ///
     /// # Example
     /// ```no_run
-    /// use nhl_rs::ClientBuilder;
+    /// use nhl_rs::{ClientBuilder, SeasonId};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), u16> {
     /// let client = ClientBuilder::new().build();
     ///
-    /// let response = client.get_games_for_season_by_id(20222023).await?;
+    /// let id = SeasonId::new(20222023).unwrap();
+    /// let response = client.get_games_for_season_by_id(id).await?;
     ///
     /// println!("2022/2023 had: {} games", response.len());
     ///
     /// Ok(())
     /// # }
     /// ```
-    pub async fn get_games_for_season_by_id(&self, id: i64) -> Result<Vec<Game>, u16> {
+    pub async fn get_games_for_season_by_id(&self, id: SeasonId) -> Result<Vec<Game>, u16> {
         let result = self.get_games().await?;
         Ok(result
             .into_iter()
             .filter(|game| game.season == id)
             .collect::<Vec<_>>())
     }
+
+    /// Get all games of a `game_type`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameType};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_games_by_game_type(GameType::Playoffs).await?;
+    ///
+    /// println!("There have been {} playoff games", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_games_by_game_type(&self, game_type: GameType) -> Result<Vec<Game>, u16> {
+        let result = self.get_games().await?;
+        Ok(result
+            .into_iter()
+            .filter(|game| game.game_type == game_type)
+            .collect::<Vec<_>>())
+    }
+
+    /// Get all games in a `game_state`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameState};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_games_by_game_state(GameState::Live).await?;
+    ///
+    /// println!("There are {} games being played", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_games_by_game_state(&self, game_state: GameState) -> Result<Vec<Game>, u16> {
+        let result = self.get_games().await?;
+        Ok(result
+            .into_iter()
+            .filter(|game| game.game_state_id == game_state)
+            .collect::<Vec<_>>())
+    }
+
+    /// Get all games in a `game_schedule_state`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameScheduleState};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client
+    ///     .get_games_by_game_schedule_state(GameScheduleState::Postponed)
+    ///     .await?;
+    ///
+    /// println!("{} games have been postponed", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_games_by_game_schedule_state(
+        &self,
+        game_schedule_state: GameScheduleState,
+    ) -> Result<Vec<Game>, u16> {
+        let result = self.get_games().await?;
+        Ok(result
+            .into_iter()
+            .filter(|game| game.game_schedule_state_id == game_schedule_state)
+            .collect::<Vec<_>>())
+    }
+
+    /// Get all games of a `game_type` for a season by the season `id`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameType, SeasonId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let id = SeasonId::new(20222023).unwrap();
+    /// let response = client
+    ///     .get_games_for_season_by_game_type(id, GameType::RegularSeason)
+    ///     .await?;
+    ///
+    /// println!("2022/2023 had: {} regular season games", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_games_for_season_by_game_type(
+        &self,
+        id: SeasonId,
+        game_type: GameType,
+    ) -> Result<Vec<Game>, u16> {
+        let result = self.get_games_for_season_by_id(id).await?;
+        Ok(result
+            .into_iter()
+            .filter(|game| game.game_type == game_type)
+            .collect::<Vec<_>>())
+    }
+
+    /// Get the play-by-play feed for a game by the game `id`.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let id = GameId::new(2023020204).unwrap();
+    /// let response = client.get_play_by_play(id).await?;
+    ///
+    /// println!("Game 2023020204 had {} plays", response.plays.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_play_by_play(&self, id: GameId) -> Result<PlayByPlayResponse, u16> {
+        let url = format!("{}/v1/gamecenter/{}/play-by-play", self.base_url, id);
+        get::<PlayByPlayResponse>(&self.transport, url).await
+    }
 }
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::state::GameType,
+    ids::{GameId, SeasonId},
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayByPlayResponse {
+    pub id: GameId,
+    pub season: SeasonId,
+    pub game_type: GameType,
+    pub game_date: Option<String>,
+    pub away_team: PlayByPlayTeam,
+    pub home_team: PlayByPlayTeam,
+    pub plays: Vec<Play>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayByPlayTeam {
+    pub id: i64,
+    pub abbrev: String,
+    pub score: Option<i64>,
+    pub sog: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Play {
+    pub event_id: i64,
+    pub period_descriptor: PeriodDescriptor,
+    pub time_in_period: String,
+    pub time_remaining: Option<String>,
+    pub situation_code: Option<String>,
+    pub home_team_defending_side: Option<String>,
+    pub type_code: i64,
+    pub type_desc_key: String,
+    pub sort_order: i64,
+    pub details: Option<PlayDetails>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodDescriptor {
+    pub number: i64,
+    pub period_type: String,
+    pub max_regulation_periods: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayDetails {
+    pub event_owner_team_id: Option<i64>,
+    pub x_coord: Option<i64>,
+    pub y_coord: Option<i64>,
+    pub zone_code: Option<String>,
+    pub shot_type: Option<String>,
+    pub reason: Option<String>,
+    pub shooting_player_id: Option<i64>,
+    pub scoring_player_id: Option<i64>,
+    pub assist1_player_id: Option<i64>,
+    pub assist2_player_id: Option<i64>,
+    pub blocking_player_id: Option<i64>,
+    pub goalie_in_net_id: Option<i64>,
+    pub away_score: Option<i64>,
+    pub home_score: Option<i64>,
+    #[serde(rename = "awaySOG")]
+    pub away_sog: Option<i64>,
+    #[serde(rename = "homeSOG")]
+    pub home_sog: Option<i64>,
+}
+
+impl Play {
+    /// The number of seconds elapsed in the period when the play occurred.
+    ///
+    /// Returns `None` if `time_in_period` is not in the `MM:SS` format.
+    pub fn seconds_in_period(&self) -> Option<i64> {
+        let (minutes, seconds) = self.time_in_period.split_once(':')?;
+        Some(minutes.parse::<i64>().ok()? * 60 + seconds.parse::<i64>().ok()?)
+    }
+
+    /// Whether the play is an unblocked shot attempt (a goal, a shot on goal or a missed shot).
+    pub fn is_unblocked_shot_attempt(&self) -> bool {
+        matches!(
+            self.type_desc_key.as_str(),
+            "goal" | "shot-on-goal" | "missed-shot"
+        )
+    }
+
+    /// Whether the play is any shot attempt, including blocked shots.
+    pub fn is_shot_attempt(&self) -> bool {
+        self.is_unblocked_shot_attempt() || self.type_desc_key == "blocked-shot"
+    }
+
+    /// The ID of the team that owns the event, if any.
+    pub fn team_id(&self) -> Option<i64> {
+        self.details.as_ref()?.event_owner_team_id
+    }
+
+    /// The ID of the shooter for shot attempts (the scorer for goals).
+    pub fn shooter_id(&self) -> Option<i64> {
+        let details = self.details.as_ref()?;
+        details.shooting_player_id.or(details.scoring_player_id)
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Declares an enum backed by the numeric ids used by the NHL API, with an `Other` variant for
+/// ids that are not known yet. The enum (de)serializes to and from the numeric id.
+macro_rules! numeric_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal,
+            )+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+            /// An id not known to this crate.
+            Other(i64),
+        }
+
+        impl $name {
+            /// The numeric id used by the NHL API.
+            pub fn id(&self) -> i64 {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Other(id) => *id,
+                }
+            }
+        }
+
+        impl From<i64> for $name {
+            fn from(id: i64) -> Self {
+                match id {
+                    $($value => $name::$variant,)+
+                    id => $name::Other(id),
+                }
+            }
+        }
+
+        impl From<$name> for i64 {
+            fn from(value: $name) -> Self {
+                value.id()
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_i64(self.id())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                i64::deserialize(deserializer).map($name::from)
+            }
+        }
+    };
+}
+
+numeric_enum! {
+    /// The type of a game (`game_type`).
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::GameType;
+    ///
+    /// assert_eq!(GameType::from(3), GameType::Playoffs);
+    /// assert_eq!(GameType::from(19), GameType::Other(19));
+    /// assert_eq!(GameType::RegularSeason.id(), 2);
+    /// ```
+    pub enum GameType {
+        Preseason = 1,
+        #[default]
+        RegularSeason = 2,
+        Playoffs = 3,
+        AllStar = 4,
+    }
+}
+
+numeric_enum! {
+    /// The progress of a game (`game_state_id`).
+    pub enum GameState {
+        #[default]
+        Scheduled = 1,
+        PreGame = 2,
+        Live = 3,
+        /// Live, in the final minutes of the game.
+        Critical = 4,
+        GameOver = 5,
+        Final = 6,
+        /// Final, with the results made official.
+        Official = 7,
+        /// Scheduled, but the start time is still to be determined.
+        ScheduledTimeTbd = 8,
+        Postponed = 9,
+    }
+}
+
+numeric_enum! {
+    /// Whether a game is being played as scheduled (`game_schedule_state_id`).
+    pub enum GameScheduleState {
+        #[default]
+        Normal = 1,
+        Postponed = 2,
+        Suspended = 3,
+        Cancelled = 4,
+        ToBeDetermined = 5,
+    }
+}
+
+impl GameState {
+    /// Whether the game is over and its score will no longer change.
+    pub fn is_completed(&self) -> bool {
+        matches!(
+            self,
+            GameState::GameOver | GameState::Final | GameState::Official
+        )
+    }
+
+    /// Whether the game is currently being played.
+    pub fn is_live(&self) -> bool {
+        matches!(self, GameState::Live | GameState::Critical)
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::{America::New_York, Tz};
+
+use crate::game::Game;
+
+impl Game {
+    /// The date of the game, parsed from `game_date`.
+    ///
+    /// Returns `None` if `game_date` does not start with a `YYYY-MM-DD` date.
+    pub fn game_date_naive(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(self.game_date.get(..10)?, "%Y-%m-%d").ok()
+    }
+
+    /// The start time of the game in eastern time, without a time zone.
+    ///
+    /// Returns `None` if `eastern_start_time` does not start with a `YYYY-MM-DDTHH:MM:SS` date time.
+    pub fn eastern_start_time_naive(&self) -> Option<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(self.eastern_start_time.get(..19)?, "%Y-%m-%dT%H:%M:%S").ok()
+    }
+
+    /// The start time of the game in the `America/New_York` time zone.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::Game;
+    ///
+    /// let game = Game {
+    ///     eastern_start_time: "2017-10-19T19:00:00".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let start = game.start_time_eastern().unwrap();
+    /// assert_eq!(start.to_rfc3339(), "2017-10-19T19:00:00-04:00");
+    /// ```
+    pub fn start_time_eastern(&self) -> Option<DateTime<Tz>> {
+        New_York
+            .from_local_datetime(&self.eastern_start_time_naive()?)
+            .earliest()
+    }
+
+    /// The start time of the game in UTC.
+    pub fn start_time_utc(&self) -> Option<DateTime<Utc>> {
+        Some(self.start_time_eastern()?.with_timezone(&Utc))
+    }
+
+    /// The start time of the game in any IANA time zone.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono_tz::Europe::Stockholm;
+    /// use nhl_rs::Game;
+    ///
+    /// let game = Game {
+    ///     eastern_start_time: "2017-10-19T19:00:00".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let start = game.start_time_in(Stockholm).unwrap();
+    /// assert_eq!(start.to_rfc3339(), "2017-10-20T01:00:00+02:00");
+    /// ```
+    pub fn start_time_in(&self, time_zone: Tz) -> Option<DateTime<Tz>> {
+        Some(self.start_time_eastern()?.with_timezone(&time_zone))
+    }
+}
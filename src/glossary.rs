@@ -1,5 +1,8 @@
-use crate::{http::get, Client};
+use std::collections::BTreeMap;
 
+use crate::{http::get, language::Language, Client};
+
+use futures::future::join_all;
 use serde::{Deserialize, Serialize};
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -21,6 +24,98 @@ pub struct GlossaryItem {
     pub last_updated: String,
 }
 
+/// A glossary term in every language it is defined in.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{GlossaryItem, Language, MultilingualGlossaryItem};
+///
+/// let item = |language_code: &str, abbreviation: &str, full_name: &str| GlossaryItem {
+///     id: 1,
+///     abbreviation: Some(abbreviation.to_string()),
+///     full_name: full_name.to_string(),
+///     language_code: language_code.to_string(),
+///     ..Default::default()
+/// };
+/// let items = MultilingualGlossaryItem::merge(vec![
+///     item("en", "G", "Goals"),
+///     item("fr", "B", "Buts"),
+/// ]);
+///
+/// assert_eq!(items.len(), 1);
+/// assert_eq!(items[0].full_name(&Language::French), Some("Buts"));
+/// assert_eq!(items[0].full_name(&Language::Czech), Some("Goals"));
+///
+/// let found = MultilingualGlossaryItem::find(&items, "b");
+/// assert_eq!(found[0].abbreviation(&Language::English), Some("G"));
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultilingualGlossaryItem {
+    pub id: i64,
+    /// The term in each language, keyed by language code.
+    pub translations: BTreeMap<String, GlossaryItem>,
+}
+
+impl MultilingualGlossaryItem {
+    /// Merges glossary items of several languages by `id`, ordered by `id`.
+    pub fn merge(items: Vec<GlossaryItem>) -> Vec<MultilingualGlossaryItem> {
+        let mut merged: BTreeMap<i64, MultilingualGlossaryItem> = BTreeMap::new();
+        for item in items {
+            let language = Language::from(item.language_code.as_str());
+            merged
+                .entry(item.id)
+                .or_insert_with(|| MultilingualGlossaryItem {
+                    id: item.id,
+                    translations: BTreeMap::new(),
+                })
+                .translations
+                .insert(language.code().to_string(), item);
+        }
+        merged.into_values().collect()
+    }
+
+    /// The glossary items whose abbreviation, in any language, is `abbreviation`, ignoring case.
+    pub fn find<'a>(
+        items: &'a [MultilingualGlossaryItem],
+        abbreviation: &str,
+    ) -> Vec<&'a MultilingualGlossaryItem> {
+        items
+            .iter()
+            .filter(|item| item.language_of_abbreviation(abbreviation).is_some())
+            .collect()
+    }
+
+    /// The term in a language, falling back to English.
+    pub fn get(&self, language: &Language) -> Option<&GlossaryItem> {
+        self.translations
+            .get(language.code())
+            .or_else(|| self.translations.get(Language::English.code()))
+    }
+
+    /// The abbreviation in a language, falling back to English.
+    pub fn abbreviation(&self, language: &Language) -> Option<&str> {
+        self.get(language)?.abbreviation.as_deref()
+    }
+
+    /// The full name in a language, falling back to English.
+    pub fn full_name(&self, language: &Language) -> Option<&str> {
+        Some(self.get(language)?.full_name.as_str())
+    }
+
+    /// The first language in which the term is abbreviated as `abbreviation`, ignoring case.
+    pub fn language_of_abbreviation(&self, abbreviation: &str) -> Option<Language> {
+        self.translations
+            .iter()
+            .find(|(_, item)| {
+                item.abbreviation
+                    .as_deref()
+                    .is_some_and(|value| value.eq_ignore_ascii_case(abbreviation.trim()))
+            })
+            .map(|(code, _)| Language::from(code.as_str()))
+    }
+}
+
 impl Client {
     /// Get a list of glossary terms.
     ///
@@ -41,7 +136,7 @@ impl Client {
     /// ```
     pub async fn get_glossary(&self) -> Result<Vec<GlossaryItem>, u16> {
         let url = format!("{}/{}/glossary", self.stats_base_url, self.language);
-        let result = get::<GlossaryResponse>(url).await;
+        let result = get::<GlossaryResponse>(&self.transport, url).await;
         match result {
             Ok(response) => Ok(response.data),
             Err(status_code) => Err(status_code),
@@ -117,4 +212,86 @@ impl Client {
             .first()
             .cloned())
     }
+
+    /// Get the glossary in every [`Language`], fetched concurrently and merged by `id`.
+    ///
+    /// Languages the glossary isn't available in are left out.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error for every language, then the corresponding HTTP error code
+    /// is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, Language};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_glossary_all_languages().await?;
+    ///
+    /// for item in response.iter().take(5) {
+    ///     println!(
+    ///         "{:?} / {:?}",
+    ///         item.full_name(&Language::English),
+    ///         item.full_name(&Language::French)
+    ///     );
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_glossary_all_languages(&self) -> Result<Vec<MultilingualGlossaryItem>, u16> {
+        let clients = Language::ALL.map(|language| self.with_language(language));
+        let results = join_all(clients.iter().map(|client| client.get_glossary())).await;
+
+        let mut items = vec![];
+        let mut error = None;
+        for result in results {
+            match result {
+                Ok(response) => items.extend(response),
+                Err(status_code) => error = error.or(Some(status_code)),
+            }
+        }
+        match error {
+            Some(status_code) if items.is_empty() => Err(status_code),
+            _ => Ok(MultilingualGlossaryItem::merge(items)),
+        }
+    }
+
+    /// Get the glossary items abbreviated as `abbreviation` in any language, e.g. the French `B`
+    /// for goals.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error for every language, then the corresponding HTTP error code
+    /// is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, Language};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_glossary_items_by_any_abbreviation("B").await?;
+    ///
+    /// for item in response {
+    ///     println!("{:?}", item.full_name(&Language::English));
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_glossary_items_by_any_abbreviation(
+        &self,
+        abbreviation: &str,
+    ) -> Result<Vec<MultilingualGlossaryItem>, u16> {
+        let result = self.get_glossary_all_languages().await?;
+        Ok(MultilingualGlossaryItem::find(&result, abbreviation)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
 }
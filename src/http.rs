@@ -1,7 +1,188 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
 use reqwest::StatusCode;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::hash::fnv1a;
+
+/// The longest sanitized URL kept in a cassette file name, before its hash.
+const MAX_KEY_LENGTH: usize = 100;
+
+/// The error code of a request replayed without a cassette. It is not an HTTP status code, so a
+/// missing cassette can't be mistaken for a recorded error of the NHL API.
+pub const NO_CASSETTE: u16 = 1000;
+/// The error code of a request whose cassette can't be read, parsed or saved.
+pub const CASSETTE_ERROR: u16 = 1001;
+
+/// How a [`Client`](crate::Client) sends its requests.
+///
+/// Recording saves every response to a cassette directory, which can then be replayed without a
+/// network, e.g. in CI.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Transport {
+    /// Send requests to the NHL API.
+    #[default]
+    Live,
+    /// Send requests to the NHL API and save the responses to a cassette directory.
+    Record(PathBuf),
+    /// Serve responses from a cassette directory only. Requests without a cassette fail with
+    /// [`NO_CASSETTE`].
+    Replay(PathBuf),
+}
+
+/// A recorded response.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{Cassette, ClientBuilder, Transport, NO_CASSETTE};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), u16> {
+/// let dir = std::env::temp_dir().join("nhl-rs-cassette-example");
+/// Cassette {
+///     method: "GET".to_string(),
+///     url: "https://api.nhle.com/stats/rest/ping".to_string(),
+///     status: 200,
+///     body: r#"{"success": true}"#.to_string(),
+/// }
+/// .save(&dir)
+/// .unwrap();
+///
+/// let client = ClientBuilder::new().transport(Transport::Replay(dir)).build();
+///
+/// assert!(client.ping().await?.success);
+/// assert_eq!(client.get_countries().await, Err(NO_CASSETTE));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cassette {
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    pub body: String,
+}
+
+impl Cassette {
+    /// The file name of the cassette of a request, without its extension: the sanitized method
+    /// and URL, and a hash of both.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::Cassette;
+    ///
+    /// let key = Cassette::key("GET", "https://api.nhle.com/stats/rest/en/country");
+    /// assert!(key.starts_with("get_api_nhle_com_stats_rest_en_country-"));
+    /// ```
+    pub fn key(method: &str, url: &str) -> String {
+        let request = format!("{} {}", method, url);
+        let without_scheme = url
+            .split_once("://")
+            .map_or(url, |(_, rest)| rest)
+            .to_lowercase();
+        let mut sanitized = method.to_lowercase();
+        for character in format!("_{}", without_scheme).chars() {
+            if character.is_ascii_alphanumeric() {
+                sanitized.push(character);
+            } else if !sanitized.ends_with('_') {
+                sanitized.push('_');
+            }
+        }
+        let sanitized = sanitized
+            .trim_end_matches('_')
+            .chars()
+            .take(MAX_KEY_LENGTH)
+            .collect::<String>();
+        format!("{}-{:016x}", sanitized, fnv1a(request.as_bytes()))
+    }
+
+    /// The path of the cassette of a request in a directory.
+    pub fn path(dir: &Path, method: &str, url: &str) -> PathBuf {
+        dir.join(format!("{}.json", Self::key(method, url)))
+    }
+
+    /// Loads the cassette of a request from a directory, or `None` if it wasn't recorded.
+    ///
+    /// # Errors
+    /// If the cassette exists but can't be read or parsed.
+    pub fn load(dir: &Path, method: &str, url: &str) -> io::Result<Option<Cassette>> {
+        let content = match fs::read_to_string(Self::path(dir, method, url)) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// Saves the cassette to a directory, creating the directory if needed.
+    ///
+    /// # Errors
+    /// If the directory or file can't be written.
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(Self::path(dir, &self.method, &self.url), content)
+    }
+}
+
+pub(crate) async fn get<T>(transport: &Transport, url: String) -> Result<T, u16>
+where
+    T: DeserializeOwned,
+{
+    let cassette = match transport {
+        Transport::Live => return get_live(url).await,
+        Transport::Record(dir) => {
+            let cassette = fetch(&url).await?;
+            cassette.save(dir).map_err(|_| CASSETTE_ERROR)?;
+            cassette
+        }
+        Transport::Replay(dir) => match Cassette::load(dir, "GET", &url) {
+            Ok(Some(cassette)) => cassette,
+            Ok(None) => return Err(NO_CASSETTE),
+            Err(_) => return Err(CASSETTE_ERROR),
+        },
+    };
+
+    if cassette.status != StatusCode::OK.as_u16() {
+        return Err(cassette.status);
+    }
+    match serde_json::from_str::<T>(&cassette.body) {
+        Ok(s) => Ok(s),
+        Err(e) => {
+            println!("{:?}", e);
+            Err(StatusCode::BAD_REQUEST.as_u16())
+        }
+    }
+}
+
+/// Sends a request, keeping the response as a cassette.
+async fn fetch(url: &str) -> Result<Cassette, u16> {
+    let response = match reqwest::get(url).await {
+        Ok(r) => r,
+        Err(e) => {
+            if e.is_status() {
+                return Err(e.status().unwrap().as_u16());
+            }
+            return Err(StatusCode::BAD_REQUEST.as_u16());
+        }
+    };
+    let status = response.status().as_u16();
+    match response.text().await {
+        Ok(body) => Ok(Cassette {
+            method: "GET".to_string(),
+            url: url.to_string(),
+            status,
+            body,
+        }),
+        Err(_) => Err(StatusCode::BAD_REQUEST.as_u16()),
+    }
+}
 
-pub(crate) async fn get<T>(url: String) -> Result<T, u16>
+async fn get_live<T>(url: String) -> Result<T, u16>
 where
     T: DeserializeOwned,
 {
@@ -0,0 +1,292 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::GameType;
+
+/// The first season of the NHL (1917-18).
+const FIRST_SEASON_START_YEAR: i64 = 1917;
+
+/// An error returned when an id is malformed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIdError {
+    kind: &'static str,
+    value: String,
+}
+
+impl ParseIdError {
+    fn new(kind: &'static str, value: impl ToString) -> Self {
+        Self {
+            kind,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}: {:?}", self.kind, self.value)
+    }
+}
+
+impl Error for ParseIdError {}
+
+/// A season id, e.g. `20222023` for the 2022-23 season.
+///
+/// Serialized as the numeric id used by the NHL API. Deserialized ids are not validated.
+///
+/// # Example
+/// ```
+/// use nhl_rs::SeasonId;
+///
+/// let season: SeasonId = "2022-23".parse().unwrap();
+///
+/// assert_eq!(season.id(), 20222023);
+/// assert_eq!(season.start_year(), 2022);
+/// assert_eq!(season.to_string(), "2022-23");
+/// assert_eq!("20222023".parse::<SeasonId>().unwrap(), season);
+/// assert!(SeasonId::new(20222024).is_err());
+/// ```
+///
+/// The default id, `0`, is a placeholder so models holding a [`SeasonId`] can derive `Default`;
+/// it is not a valid season and [`SeasonId::new`] rejects it.
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct SeasonId(i64);
+
+impl SeasonId {
+    /// Creates a [`SeasonId`] from its numeric form, e.g. `20222023`.
+    ///
+    /// # Errors
+    /// Returns an error if the id is not a start year followed by the next year.
+    pub fn new(id: i64) -> Result<Self, ParseIdError> {
+        let (start_year, end_year) = (id / 10000, id % 10000);
+        if start_year < FIRST_SEASON_START_YEAR || end_year != start_year + 1 {
+            return Err(ParseIdError::new("season id", id));
+        }
+        Ok(Self(id))
+    }
+
+    /// Creates the [`SeasonId`] of the season starting in `start_year`.
+    ///
+    /// # Errors
+    /// Returns an error if the year is before the first NHL season or after 9998.
+    pub fn from_start_year(start_year: i64) -> Result<Self, ParseIdError> {
+        if !(FIRST_SEASON_START_YEAR..=9998).contains(&start_year) {
+            return Err(ParseIdError::new("season start year", start_year));
+        }
+        Ok(Self(start_year * 10000 + start_year + 1))
+    }
+
+    /// The numeric id, e.g. `20222023`.
+    pub fn id(&self) -> i64 {
+        self.0
+    }
+
+    pub fn start_year(&self) -> i64 {
+        self.0 / 10000
+    }
+
+    pub fn end_year(&self) -> i64 {
+        self.0 % 10000
+    }
+}
+
+impl fmt::Display for SeasonId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}", self.start_year(), self.end_year() % 100)
+    }
+}
+
+impl FromStr for SeasonId {
+    type Err = ParseIdError;
+
+    /// Parses `2022-23`, `2022-2023` or `20222023`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseIdError::new("season id", s);
+        let s = s.trim();
+
+        let Some((start, end)) = s.split_once('-') else {
+            return Self::new(s.parse().map_err(|_| error())?);
+        };
+        let start_year = start.parse::<i64>().map_err(|_| error())?;
+        let season = Self::from_start_year(start_year).map_err(|_| error())?;
+        let matches_end = match end.len() {
+            2 => end.parse::<i64>().ok() == Some(season.end_year() % 100),
+            4 => end.parse::<i64>().ok() == Some(season.end_year()),
+            _ => false,
+        };
+        if !matches_end {
+            return Err(error());
+        }
+        Ok(season)
+    }
+}
+
+impl TryFrom<i64> for SeasonId {
+    type Error = ParseIdError;
+
+    fn try_from(id: i64) -> Result<Self, Self::Error> {
+        Self::new(id)
+    }
+}
+
+impl From<SeasonId> for i64 {
+    fn from(season: SeasonId) -> Self {
+        season.0
+    }
+}
+
+/// A game id, e.g. `2017020120`: the season start year, the two digit game type and the game number.
+///
+/// Serialized as the numeric id used by the NHL API. Deserialized ids are not validated.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{GameId, GameType, SeasonId};
+///
+/// let game: GameId = "2017020120".parse().unwrap();
+///
+/// assert_eq!(game.season(), SeasonId::new(20172018).unwrap());
+/// assert_eq!(game.game_type(), GameType::RegularSeason);
+/// assert_eq!(game.number(), 120);
+/// assert!(GameId::new(17020120).is_err());
+/// ```
+///
+/// The default id, `0`, is a placeholder so models holding a [`GameId`] can derive `Default`;
+/// it is not a valid game and [`GameId::new`] rejects it.
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct GameId(i64);
+
+impl GameId {
+    /// Creates a [`GameId`] from its numeric form, e.g. `2017020120`.
+    ///
+    /// # Errors
+    /// Returns an error if the id does not start with a valid season start year or has no game
+    /// number.
+    pub fn new(id: i64) -> Result<Self, ParseIdError> {
+        let start_year = id / 1_000_000;
+        if SeasonId::from_start_year(start_year).is_err() || id % 10000 == 0 {
+            return Err(ParseIdError::new("game id", id));
+        }
+        Ok(Self(id))
+    }
+
+    /// The numeric id, e.g. `2017020120`.
+    pub fn id(&self) -> i64 {
+        self.0
+    }
+
+    /// The season the game is played in.
+    pub fn season(&self) -> SeasonId {
+        let start_year = self.0 / 1_000_000;
+        SeasonId(start_year * 10000 + start_year + 1)
+    }
+
+    pub fn game_type(&self) -> GameType {
+        GameType::from(self.0 / 10000 % 100)
+    }
+
+    /// The game number within the season and game type.
+    pub fn number(&self) -> i64 {
+        self.0 % 10000
+    }
+}
+
+impl fmt::Display for GameId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for GameId {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| ParseIdError::new("game id", s))?;
+        Self::new(id)
+    }
+}
+
+impl TryFrom<i64> for GameId {
+    type Error = ParseIdError;
+
+    fn try_from(id: i64) -> Result<Self, Self::Error> {
+        Self::new(id)
+    }
+}
+
+impl From<GameId> for i64 {
+    fn from(game: GameId) -> Self {
+        game.0
+    }
+}
+
+/// A team id, e.g. `10` for the Toronto Maple Leafs.
+///
+/// Serialized as the numeric id used by the NHL API. Deserialized ids are not validated.
+///
+/// The default id, `0`, is a placeholder so models holding a [`TeamId`] can derive `Default`;
+/// it is not a valid team and [`TeamId::new`] rejects it.
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct TeamId(i64);
+
+impl TeamId {
+    /// Creates a [`TeamId`].
+    ///
+    /// # Errors
+    /// Returns an error if the id is not positive.
+    pub fn new(id: i64) -> Result<Self, ParseIdError> {
+        if id <= 0 {
+            return Err(ParseIdError::new("team id", id));
+        }
+        Ok(Self(id))
+    }
+
+    pub fn id(&self) -> i64 {
+        self.0
+    }
+}
+
+impl fmt::Display for TeamId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for TeamId {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| ParseIdError::new("team id", s))?;
+        Self::new(id)
+    }
+}
+
+impl TryFrom<i64> for TeamId {
+    type Error = ParseIdError;
+
+    fn try_from(id: i64) -> Result<Self, Self::Error> {
+        Self::new(id)
+    }
+}
+
+impl From<TeamId> for i64 {
+    fn from(team: TeamId) -> Self {
+        team.0
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A language of the NHL API.
+///
+/// Codes not known to this crate are kept as [`Language::Other`].
+///
+/// # Example
+/// ```
+/// use nhl_rs::Language;
+///
+/// assert_eq!(Language::from("fr"), Language::French);
+/// assert_eq!(Language::from("FR"), Language::French);
+/// assert_eq!(Language::from("it").code(), "it");
+/// assert_eq!(Language::Czech.to_string(), "cs");
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Language {
+    #[default]
+    English,
+    French,
+    Czech,
+    German,
+    Spanish,
+    Finnish,
+    Russian,
+    Slovak,
+    Swedish,
+    /// A language code not known to this crate.
+    Other(String),
+}
+
+impl Language {
+    /// Every language known to this crate.
+    pub const ALL: [Language; 9] = [
+        Language::English,
+        Language::French,
+        Language::Czech,
+        Language::German,
+        Language::Spanish,
+        Language::Finnish,
+        Language::Russian,
+        Language::Slovak,
+        Language::Swedish,
+    ];
+
+    /// The two letter code of the language, e.g. `fr`.
+    pub fn code(&self) -> &str {
+        match self {
+            Self::English => "en",
+            Self::French => "fr",
+            Self::Czech => "cs",
+            Self::German => "de",
+            Self::Spanish => "es",
+            Self::Finnish => "fi",
+            Self::Russian => "ru",
+            Self::Slovak => "sk",
+            Self::Swedish => "sv",
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for Language {
+    fn from(code: &str) -> Self {
+        let code = code.trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code() == code)
+            .unwrap_or(Self::Other(code))
+    }
+}
+
+impl From<String> for Language {
+    fn from(code: String) -> Self {
+        Self::from(code.as_str())
+    }
+}
+
+impl From<Language> for String {
+    fn from(language: Language) -> Self {
+        language.code().to_string()
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
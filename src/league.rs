@@ -0,0 +1,498 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::Game,
+    http::get,
+    ids::{SeasonId, TeamId},
+    localized::LocalizedString,
+    names,
+    standings::{Standings, StandingsTable},
+    stats::{StatsQuery, TeamSummary},
+    team::Team,
+    Client,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConferenceResponse {
+    pub data: Vec<Conference>,
+    pub total: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Conference {
+    pub id: i64,
+    pub abbrev: Option<String>,
+    pub name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DivisionResponse {
+    pub data: Vec<Division>,
+    pub total: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Division {
+    pub id: i64,
+    pub abbrev: Option<String>,
+    pub name: String,
+    pub conference_id: Option<i64>,
+}
+
+/// The standings of the web API on a date.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyStandings {
+    pub standings: Vec<DailyStandingsRow>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyStandingsRow {
+    pub season_id: SeasonId,
+    pub date: Option<String>,
+    pub team_name: LocalizedString,
+    pub team_abbrev: LocalizedString,
+    pub conference_abbrev: Option<String>,
+    pub conference_name: Option<String>,
+    pub division_abbrev: Option<String>,
+    pub division_name: Option<String>,
+    pub games_played: i64,
+    pub wins: i64,
+    pub losses: i64,
+    pub ot_losses: Option<i64>,
+    pub ties: Option<i64>,
+    pub points: i64,
+    pub league_sequence: Option<i64>,
+    pub conference_sequence: Option<i64>,
+    pub division_sequence: Option<i64>,
+}
+
+/// The conference and division of a team in a season.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamAlignment {
+    pub team_id: TeamId,
+    pub conference: Option<String>,
+    pub division: Option<String>,
+    /// The official rank of the team in its division, when known.
+    pub division_rank: Option<i64>,
+}
+
+/// A team ranked differently in its division by [`Standings::division_mismatches`] and by the
+/// NHL.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DivisionRankMismatch {
+    pub team_id: TeamId,
+    pub division: String,
+    pub computed_rank: i64,
+    pub official_rank: i64,
+}
+
+/// Which teams belonged to which conference and division in a season.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{Game, LeagueStructure, SeasonId, TeamId};
+///
+/// let [bruins, leafs, rangers] = [6, 10, 3].map(|id| TeamId::new(id).unwrap());
+/// let structure = LeagueStructure::new(SeasonId::new(20232024).unwrap())
+///     .assign(bruins, Some("Eastern"), Some("Atlantic"))
+///     .assign(leafs, Some("Eastern"), Some("Atlantic"))
+///     .assign(rangers, Some("Eastern"), Some("Metropolitan"));
+///
+/// assert_eq!(structure.division_of(leafs), Some("Atlantic"));
+/// assert_eq!(structure.divisions()["Atlantic"], vec![bruins, leafs]);
+///
+/// let game = Game {
+///     home_team_id: bruins,
+///     visiting_team_id: leafs,
+///     ..Default::default()
+/// };
+/// assert!(structure.is_divisional_game(&game));
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueStructure {
+    pub season: SeasonId,
+    pub teams: HashMap<TeamId, TeamAlignment>,
+}
+
+impl LeagueStructure {
+    /// Creates an empty structure for a season.
+    pub fn new(season: SeasonId) -> Self {
+        Self {
+            season,
+            teams: HashMap::new(),
+        }
+    }
+
+    /// Assigns a team to a conference and division.
+    pub fn assign(
+        mut self,
+        team_id: TeamId,
+        conference: Option<&str>,
+        division: Option<&str>,
+    ) -> Self {
+        self.teams.insert(
+            team_id,
+            TeamAlignment {
+                team_id,
+                conference: conference.map(str::to_string),
+                division: division.map(str::to_string),
+                division_rank: None,
+            },
+        );
+        self
+    }
+
+    /// Sets the official rank of a team in its division.
+    pub fn rank(mut self, team_id: TeamId, division_rank: i64) -> Self {
+        if let Some(team) = self.teams.get_mut(&team_id) {
+            team.division_rank = Some(division_rank);
+        }
+        self
+    }
+
+    /// Builds the structure of a season from the standings of one of its dates.
+    ///
+    /// Standings rows are linked to `teams` by name, and then by tri-code. Names and tri-codes
+    /// have been reused, so `teams` should only hold the teams that played in the season.
+    pub fn from_standings(
+        season: SeasonId,
+        standings: &[DailyStandingsRow],
+        teams: &[Team],
+    ) -> Self {
+        let mut structure = Self::new(season);
+        for row in standings {
+            let team = teams
+                .iter()
+                .find(|team| names::same_name(&team.full_name, &row.team_name.default))
+                .or_else(|| {
+                    teams
+                        .iter()
+                        .find(|team| team.tri_code.eq_ignore_ascii_case(&row.team_abbrev.default))
+                });
+            if let Some(team) = team {
+                structure = structure.assign(
+                    team.id,
+                    row.conference_name.as_deref(),
+                    row.division_name.as_deref(),
+                );
+                if let Some(division_rank) = row.division_sequence {
+                    structure = structure.rank(team.id, division_rank);
+                }
+            }
+        }
+        structure
+    }
+
+    pub fn conference_of(&self, team_id: TeamId) -> Option<&str> {
+        self.teams.get(&team_id)?.conference.as_deref()
+    }
+
+    pub fn division_of(&self, team_id: TeamId) -> Option<&str> {
+        self.teams.get(&team_id)?.division.as_deref()
+    }
+
+    /// The teams of each conference, ordered by id.
+    pub fn conferences(&self) -> BTreeMap<String, Vec<TeamId>> {
+        members(self.conference_groups())
+    }
+
+    /// The teams of each division, ordered by id.
+    pub fn divisions(&self) -> BTreeMap<String, Vec<TeamId>> {
+        members(self.division_groups())
+    }
+
+    /// A map of team id to conference name, as used by [`Standings::grouped`].
+    pub fn conference_groups(&self) -> HashMap<TeamId, String> {
+        self.teams
+            .values()
+            .filter_map(|team| Some((team.team_id, team.conference.clone()?)))
+            .collect()
+    }
+
+    /// A map of team id to division name, as used by [`Standings::grouped`].
+    pub fn division_groups(&self) -> HashMap<TeamId, String> {
+        self.teams
+            .values()
+            .filter_map(|team| Some((team.team_id, team.division.clone()?)))
+            .collect()
+    }
+
+    /// Whether both teams of a game belong to the same division.
+    pub fn is_divisional_game(&self, game: &Game) -> bool {
+        match (
+            self.division_of(game.home_team_id),
+            self.division_of(game.visiting_team_id),
+        ) {
+            (Some(home), Some(visiting)) => home == visiting,
+            _ => false,
+        }
+    }
+
+    /// Whether both teams of a game belong to the same conference.
+    pub fn is_conference_game(&self, game: &Game) -> bool {
+        match (
+            self.conference_of(game.home_team_id),
+            self.conference_of(game.visiting_team_id),
+        ) {
+            (Some(home), Some(visiting)) => home == visiting,
+            _ => false,
+        }
+    }
+
+    /// The games played within each division.
+    pub fn divisional_games(&self, games: &[Game]) -> BTreeMap<String, Vec<Game>> {
+        let mut divisions: BTreeMap<String, Vec<Game>> = BTreeMap::new();
+        for game in games {
+            if self.is_divisional_game(game) {
+                if let Some(division) = self.division_of(game.home_team_id) {
+                    divisions
+                        .entry(division.to_string())
+                        .or_default()
+                        .push(game.clone());
+                }
+            }
+        }
+        divisions
+    }
+}
+
+fn members(groups: HashMap<TeamId, String>) -> BTreeMap<String, Vec<TeamId>> {
+    let mut members: BTreeMap<String, Vec<TeamId>> = BTreeMap::new();
+    for (team_id, group) in groups {
+        members.entry(group).or_default().push(team_id);
+    }
+    for team_ids in members.values_mut() {
+        team_ids.sort();
+    }
+    members
+}
+
+impl Standings {
+    /// One table per division of a season.
+    ///
+    /// Tiebreakers comparing tied teams (e.g. head-to-head points) only use games between the
+    /// teams of the division.
+    pub fn divisions(&self, structure: &LeagueStructure) -> Vec<StandingsTable> {
+        self.grouped(&structure.division_groups())
+    }
+
+    /// One table per conference of a season.
+    pub fn conferences(&self, structure: &LeagueStructure) -> Vec<StandingsTable> {
+        self.grouped(&structure.conference_groups())
+    }
+
+    /// The teams whose computed division rank differs from the official rank of `structure`,
+    /// e.g. because a historical tiebreaker isn't applied.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{Game, GameState, GameType, LeagueStructure, RuleSet, SeasonId, Standings, TeamId};
+    ///
+    /// let [bruins, leafs] = [6, 10].map(|id| TeamId::new(id).unwrap());
+    /// let game = Game {
+    ///     game_type: GameType::RegularSeason,
+    ///     game_state_id: GameState::Official,
+    ///     game_date: "2024-01-01".to_string(),
+    ///     home_team_id: bruins,
+    ///     home_score: 3,
+    ///     visiting_team_id: leafs,
+    ///     visiting_score: 1,
+    ///     period: Some(3),
+    ///     ..Default::default()
+    /// };
+    /// let season = SeasonId::new(20232024).unwrap();
+    /// let standings = Standings::compute(&[game], RuleSet::for_season(season), None);
+    ///
+    /// let structure = LeagueStructure::new(season)
+    ///     .assign(bruins, Some("Eastern"), Some("Atlantic"))
+    ///     .assign(leafs, Some("Eastern"), Some("Atlantic"))
+    ///     .rank(bruins, 2)
+    ///     .rank(leafs, 1);
+    ///
+    /// let mismatches = standings.division_mismatches(&structure);
+    /// assert_eq!(mismatches.len(), 2);
+    /// assert_eq!(mismatches[0].team_id, bruins);
+    /// assert_eq!(mismatches[0].computed_rank, 1);
+    /// ```
+    pub fn division_mismatches(&self, structure: &LeagueStructure) -> Vec<DivisionRankMismatch> {
+        let mut mismatches = Vec::new();
+        for table in self.divisions(structure) {
+            for row in table.rows {
+                let official_rank = structure
+                    .teams
+                    .get(&row.record.team_id)
+                    .and_then(|team| team.division_rank);
+                let computed_rank = row.rank as i64;
+                if let Some(official_rank) = official_rank {
+                    if official_rank != computed_rank {
+                        mismatches.push(DivisionRankMismatch {
+                            team_id: row.record.team_id,
+                            division: table.name.clone(),
+                            computed_rank,
+                            official_rank,
+                        });
+                    }
+                }
+            }
+        }
+        mismatches
+    }
+}
+
+impl Client {
+    /// Get a list of conferences.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_conferences().await?;
+    ///
+    /// println!("The NHL has had {} conferences", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_conferences(&self) -> Result<Vec<Conference>, u16> {
+        let url = format!("{}/{}/conference", self.stats_base_url, self.language);
+        let result = get::<ConferenceResponse>(&self.transport, url).await;
+        match result {
+            Ok(response) => Ok(response.data),
+            Err(status_code) => Err(status_code),
+        }
+    }
+
+    /// Get a list of divisions.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_divisions().await?;
+    ///
+    /// println!("The NHL has had {} divisions", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_divisions(&self) -> Result<Vec<Division>, u16> {
+        let url = format!("{}/{}/division", self.stats_base_url, self.language);
+        let result = get::<DivisionResponse>(&self.transport, url).await;
+        match result {
+            Ok(response) => Ok(response.data),
+            Err(status_code) => Err(status_code),
+        }
+    }
+
+    /// Get the standings on a `YYYY-MM-DD` date from the web API.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_standings_by_date("1994-04-14").await?;
+    ///
+    /// for row in response.standings {
+    ///     println!("{} ({:?}): {} points", row.team_name, row.division_name, row.points);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_standings_by_date(&self, date: &str) -> Result<DailyStandings, u16> {
+        let url = format!("{}/v1/standings/{}", self.base_url, date);
+        get::<DailyStandings>(&self.transport, url).await
+    }
+
+    /// Get the conferences and divisions of a season, as they were at the end of its regular
+    /// season.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, SeasonId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let season = SeasonId::new(19931994).unwrap();
+    /// let structure = client.get_league_structure(season).await?.unwrap();
+    /// let standings = client.get_standings_for_season(season, None).await?;
+    ///
+    /// for table in standings.divisions(&structure) {
+    ///     println!("{}: {:?}", table.name, table.rows[0].record.team_id);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_league_structure(
+        &self,
+        season: SeasonId,
+    ) -> Result<Option<LeagueStructure>, u16> {
+        let Some(details) = self.get_season_by_id(season).await? else {
+            return Ok(None);
+        };
+        let date = details
+            .regular_season_end_date
+            .get(..10)
+            .unwrap_or(&details.regular_season_end_date);
+        let standings = self.get_standings_by_date(date).await?;
+
+        let query = StatsQuery::new().season(season).limit(-1);
+        let season_team_ids = self
+            .get_stats::<TeamSummary>(&query)
+            .await?
+            .data
+            .into_iter()
+            .map(|row| row.team_id)
+            .collect::<Vec<_>>();
+        let teams = self
+            .get_teams()
+            .await?
+            .into_iter()
+            .filter(|team| season_team_ids.contains(&team.id))
+            .collect::<Vec<_>>();
+
+        Ok(Some(LeagueStructure::from_standings(
+            season,
+            &standings.standings,
+            &teams,
+        )))
+    }
+}
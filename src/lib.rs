@@ -1,11 +1,78 @@
+pub use calendar::ScheduleCalendar;
 pub use client::Client;
 pub use client::ClientBuilder;
+pub use country::Country;
+pub use draft::{
+    Draft, DraftPick, DraftPicksResponse, DraftRanking, DraftRankingCategory, DraftRankingsResponse,
+};
+pub use edge::{
+    EdgeCount, EdgeCountAverage, EdgeMeasurement, EdgePlayer, EdgeSeason, EdgeSelection, EdgeTeam,
+    EdgeValue, GoalieEdgeDetail, SaveLocation, SkaterEdgeDetail, SkatingSpeed, TeamEdgeDetail,
+    ZoneTime,
+};
+pub use franchise::Franchise;
+pub use franchise_history::{FranchiseEra, FranchiseHistory};
+pub use game::{
+    Entity, Game, GameMetadataResponse, GameScheduleState, GameState, GameType, PeriodDescriptor,
+    Play, PlayByPlayResponse, PlayByPlayTeam, PlayDetails, Property,
+};
+pub use glossary::{GlossaryItem, MultilingualGlossaryItem};
+pub use http::{Cassette, Transport, CASSETTE_ERROR, NO_CASSETTE};
+pub use ids::{GameId, ParseIdError, SeasonId, TeamId};
+pub use language::Language;
+pub use league::{
+    Conference, DailyStandings, DailyStandingsRow, Division, DivisionRankMismatch, LeagueStructure,
+    TeamAlignment,
+};
+pub use localized::LocalizedString;
+pub use names::NameMatch;
+pub use players::{Player, PlayerSearch, PlayerSearchResult};
+pub use records::{
+    FranchiseGoalieRecord, FranchiseSeasonResult, FranchiseSkaterRecord, FranchiseTeamTotals,
+};
+pub use rink::{normalize_plays, HeatMap, NormalizedEvent, RinkPoint, Zone};
+pub use rules::{GameResult, OvertimeFormat, RuleSet};
+pub use schema::{FieldType, RetypedField, SchemaDrift, SchemaModel};
+pub use season::{ComponentSeason, Season};
+pub use shot_map::{MarkerColor, ShotMap, ShotMarker};
+pub use standings::{
+    PointsRuleset, Standings, StandingsRow, StandingsTable, TeamRecord, Tiebreaker,
+    TiebreakerExplanation,
+};
+pub use stats::{SortDirection, StatsQuery};
+pub use team::{GameSide, Team};
+pub use xg::{
+    GameXg, ShotFeatures, ShotType, ShotTypeCoefficients, ShotXg, Strength, StrengthCoefficients,
+    XgCoefficients, XgModel,
+};
 
+mod calendar;
 mod client;
+pub mod codegen;
 mod country;
+mod date;
+mod draft;
+mod edge;
 mod franchise;
+mod franchise_history;
 mod game;
 mod glossary;
+mod hash;
 pub(crate) mod http;
+mod ids;
+mod language;
+mod league;
+mod localized;
+mod names;
 mod ping;
+mod players;
+mod records;
+pub mod rink;
+pub mod rules;
+mod schema;
 mod season;
+mod shot_map;
+mod standings;
+pub mod stats;
+mod team;
+mod xg;
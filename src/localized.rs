@@ -0,0 +1,73 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{language::Language, Client};
+
+/// A string of the web API that can be translated, e.g. a team name.
+///
+/// The web API sends a `default` string, usually in English, and translations keyed by language
+/// code, e.g. `{"default": "Montréal Canadiens", "fr": "Canadiens de Montréal"}`.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{Language, LocalizedString};
+///
+/// let name: LocalizedString = serde_json::from_str(
+///     r#"{"default": "Montréal Canadiens", "fr": "Canadiens de Montréal"}"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(name.get(&Language::French), "Canadiens de Montréal");
+/// assert_eq!(name.get(&Language::Czech), "Montréal Canadiens");
+/// assert_eq!(name.translation(&Language::Czech), None);
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LocalizedString {
+    pub default: String,
+    /// The translations of the string, keyed by language code.
+    #[serde(flatten)]
+    pub translations: BTreeMap<String, String>,
+}
+
+impl LocalizedString {
+    /// The translation of the string in a language, if there is one.
+    pub fn translation(&self, language: &Language) -> Option<&str> {
+        self.translations.get(language.code()).map(String::as_str)
+    }
+
+    /// The string in a language, falling back to the default string.
+    pub fn get(&self, language: &Language) -> &str {
+        self.translation(language).unwrap_or(&self.default)
+    }
+
+    /// The languages the string is translated in.
+    pub fn languages(&self) -> Vec<Language> {
+        self.translations
+            .keys()
+            .map(|code| Language::from(code.as_str()))
+            .collect()
+    }
+}
+
+impl From<&str> for LocalizedString {
+    fn from(default: &str) -> Self {
+        Self {
+            default: default.to_string(),
+            translations: BTreeMap::new(),
+        }
+    }
+}
+
+impl fmt::Display for LocalizedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.default)
+    }
+}
+
+impl Client {
+    /// A localized string in the language of the client, falling back to the default string.
+    pub fn localize<'a>(&self, string: &'a LocalizedString) -> &'a str {
+        string.get(&self.language)
+    }
+}
@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// The lowest score of a candidate returned by a ranked search.
+const MIN_SEARCH_SCORE: f64 = 0.5;
+
+/// A candidate of a ranked search by name, e.g. of
+/// [`Client::search_countries`](crate::Client::search_countries) or
+/// [`PlayerSearch::matches`](crate::PlayerSearch::matches).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NameMatch<T> {
+    pub item: T,
+    /// How well the name matches, from `0.0` to `1.0` (equal once normalized).
+    pub score: f64,
+}
+
+/// Groups of equivalent given names, e.g. nicknames and spellings of the same name.
+const GIVEN_NAME_GROUPS: [&[&str]; 30] = [
+    &[
+        "alex",
+        "alexander",
+        "alexandre",
+        "aleksander",
+        "alexei",
+        "aleksi",
+    ],
+    &["andy", "drew", "andrew", "andreas", "andre"],
+    &["ben", "benjamin"],
+    &["bill", "billy", "will", "willie", "william"],
+    &["bob", "bobby", "rob", "robbie", "robert"],
+    &["chris", "christopher", "christian"],
+    &["dan", "danny", "daniel"],
+    &["dave", "david"],
+    &["ed", "eddie", "edward"],
+    &["fred", "freddie", "frederik", "frederick", "fredrik"],
+    &["greg", "gregory"],
+    &["jake", "jacob", "jakob"],
+    &["jim", "jimmy", "james"],
+    &["joe", "joey", "joseph"],
+    &["jon", "jonathan", "jonathon"],
+    &["ken", "kenny", "kenneth"],
+    &["matt", "matthew", "mathew", "matthias", "mathias"],
+    &["max", "maxim", "maxime", "maximilian"],
+    &["mike", "mikey", "michael", "mikhail"],
+    &["mitch", "mitchell"],
+    &["nate", "nathan", "nathaniel"],
+    &[
+        "nick", "nicholas", "nicolas", "nicklas", "niklas", "nikolai",
+    ],
+    &["pat", "patrick"],
+    &["rick", "ricky", "rich", "richard"],
+    &["ron", "ronnie", "ronald"],
+    &["sam", "sammy", "samuel"],
+    &["steve", "steven", "stephen"],
+    &["tom", "tommy", "thomas"],
+    &["tony", "anthony", "anton"],
+    &["zach", "zack", "zachary", "zak"],
+];
+
+/// Folds a name for comparison: diacritics are removed, letters lowercased and punctuation
+/// replaced by single spaces, so `Tim Stützle` becomes `tim stutzle` and `O'Reilly` becomes
+/// `o reilly`.
+pub(crate) fn normalize(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for c in name.nfkd().filter(|c| !is_combining_mark(*c)) {
+        match c {
+            // Letters that do not decompose into a base letter and a mark.
+            'ø' | 'Ø' => folded.push('o'),
+            'æ' | 'Æ' => folded.push_str("ae"),
+            'œ' | 'Œ' => folded.push_str("oe"),
+            'ß' => folded.push_str("ss"),
+            'ł' | 'Ł' => folded.push('l'),
+            'đ' | 'Đ' => folded.push('d'),
+            'þ' | 'Þ' => folded.push_str("th"),
+            c if c.is_alphanumeric() => folded.extend(c.to_lowercase()),
+            _ => folded.push(' '),
+        }
+    }
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Whether two names are equal once normalized, e.g. `St Louis` and `St. Louis`.
+pub(crate) fn same_name(a: &str, b: &str) -> bool {
+    normalize(a) == normalize(b)
+}
+
+/// The Levenshtein distance between two strings, in characters.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// How similar two already normalized strings are, from `0.0` to `1.0` (equal).
+pub(crate) fn similarity(a: &str, b: &str) -> f64 {
+    let length = a.chars().count().max(b.chars().count());
+    if length == 0 {
+        return 1.0;
+    }
+    1.0 - edit_distance(a, b) as f64 / length as f64
+}
+
+/// Whether two normalized given names are nicknames or spellings of the same name.
+pub(crate) fn same_given_name(a: &str, b: &str) -> bool {
+    a == b
+        || GIVEN_NAME_GROUPS
+            .iter()
+            .any(|group| group.contains(&a) && group.contains(&b))
+}
+
+/// How well a query matches a name, from `0.0` to `1.0`.
+///
+/// A single word query is compared to every word of the name, e.g. `stutzle` matches
+/// `Tim Stützle`. Longer queries compare their last word to the last name and the other words to
+/// the given names, accepting nicknames, e.g. `Alex Ovechkin` matches `Alexander Ovechkin`.
+pub(crate) fn match_score(query: &str, name: &str) -> f64 {
+    let query = normalize(query);
+    let name = normalize(name);
+    if query.is_empty() || name.is_empty() {
+        return 0.0;
+    }
+    let whole = similarity(&query, &name);
+
+    let query_words = query.split(' ').collect::<Vec<_>>();
+    let name_words = name.split(' ').collect::<Vec<_>>();
+    let words = match query_words.as_slice() {
+        [word] => name_words
+            .iter()
+            .map(|name_word| similarity(word, name_word))
+            .fold(0.0, f64::max),
+        [given @ .., last] => {
+            let (name_given, name_last) = name_words.split_at(name_words.len() - 1);
+            let last_score = similarity(last, name_last[0]);
+            let given_score = given
+                .iter()
+                .map(|word| {
+                    name_given
+                        .iter()
+                        .map(|name_word| {
+                            if same_given_name(word, name_word) {
+                                1.0
+                            } else {
+                                similarity(word, name_word)
+                            }
+                        })
+                        .fold(0.0, f64::max)
+                })
+                .sum::<f64>()
+                / given.len() as f64;
+            // The last name identifies a player better than the given name.
+            0.6 * last_score + 0.4 * given_score
+        }
+        [] => 0.0,
+    };
+
+    whole.max(words)
+}
+
+/// Ranks items by how well any of their names match a query, best match first.
+pub(crate) fn rank<T, F>(items: Vec<T>, query: &str, names: F) -> Vec<NameMatch<T>>
+where
+    F: Fn(&T) -> Vec<&str>,
+{
+    let mut matches = items
+        .into_iter()
+        .filter_map(|item| {
+            let score = names(&item)
+                .iter()
+                .map(|name| match_score(query, name))
+                .fold(0.0, f64::max);
+            (score >= MIN_SEARCH_SCORE).then_some(NameMatch { item, score })
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_name_ignores_diacritics_case_and_punctuation() {
+        assert!(same_name("Montreal Canadiens", "Montréal Canadiens"));
+        assert!(same_name("St Louis", "St. Louis"));
+        assert!(same_name("st louis blues", "St. Louis Blues"));
+        assert!(!same_name("St Louis", "St. Paul"));
+    }
+
+    #[test]
+    fn normalize_folds_names() {
+        assert_eq!(normalize("Montréal Canadiens"), "montreal canadiens");
+        assert_eq!(normalize("St. Louis"), "st louis");
+        assert_eq!(normalize("Tim Stützle"), "tim stutzle");
+        assert_eq!(normalize("O'Reilly"), "o reilly");
+    }
+}
@@ -28,6 +28,6 @@ impl Client {
     /// ```
     pub async fn ping(&self) -> Result<PingResponse, u16> {
         let url = format!("{}/ping", self.stats_base_url);
-        get::<PingResponse>(url).await
+        get::<PingResponse>(&self.transport, url).await
     }
 }
@@ -0,0 +1,332 @@
+use reqwest::{StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    http::get,
+    ids::TeamId,
+    names::{self, NameMatch},
+    Client,
+};
+
+/// The lowest [`NameMatch::score`] of a player search returned by default.
+const DEFAULT_MIN_SCORE: f64 = 0.8;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerResponse {
+    pub data: Vec<Player>,
+    pub total: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Player {
+    pub id: i64,
+    pub first_name: String,
+    pub last_name: String,
+    pub full_name: String,
+    /// `YYYY-MM-DD`.
+    pub birth_date: Option<String>,
+    pub birth_city: Option<String>,
+    pub birth_state_province: Option<String>,
+    /// The three letter country code, e.g. `CAN`.
+    pub birth_country: Option<String>,
+    pub nationality_code: Option<String>,
+    /// `C`, `L`, `R`, `D` or `G`.
+    pub position_code: Option<String>,
+    pub shoots_catches: Option<String>,
+    pub sweater_number: Option<i64>,
+    pub height_in_centimeters: Option<i64>,
+    pub weight_in_kilograms: Option<i64>,
+    pub current_team_id: Option<TeamId>,
+}
+
+impl Player {
+    /// Whether the player is active, i.e. has a current team.
+    pub fn is_active(&self) -> bool {
+        self.current_team_id.is_some()
+    }
+}
+
+/// A result of the player search endpoint.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerSearchResult {
+    pub player_id: String,
+    pub name: String,
+    pub position_code: Option<String>,
+    pub team_id: Option<String>,
+    pub team_abbrev: Option<String>,
+    pub last_team_id: Option<String>,
+    pub last_team_abbrev: Option<String>,
+    pub last_season_id: Option<String>,
+    pub sweater_number: Option<i64>,
+    pub active: bool,
+    pub height_in_centimeters: Option<i64>,
+    pub weight_in_kilograms: Option<i64>,
+    pub birth_city: Option<String>,
+    pub birth_state_province: Option<String>,
+    pub birth_country: Option<String>,
+}
+
+impl PlayerSearchResult {
+    /// The numeric player id.
+    pub fn id(&self) -> Option<i64> {
+        self.player_id.parse().ok()
+    }
+}
+
+/// A local, fuzzy search of players by name.
+///
+/// Names are compared without diacritics, case or punctuation, tolerating typos and common
+/// nicknames. Birth date, position and active status narrow down players sharing a name.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{Player, PlayerSearch};
+///
+/// let players = [
+///     Player {
+///         id: 8482116,
+///         first_name: "Tim".to_string(),
+///         last_name: "Stützle".to_string(),
+///         full_name: "Tim Stützle".to_string(),
+///         birth_date: Some("2002-01-15".to_string()),
+///         position_code: Some("C".to_string()),
+///         ..Default::default()
+///     },
+///     Player {
+///         id: 8471214,
+///         first_name: "Alexander".to_string(),
+///         last_name: "Ovechkin".to_string(),
+///         full_name: "Alexander Ovechkin".to_string(),
+///         position_code: Some("L".to_string()),
+///         ..Default::default()
+///     },
+/// ];
+///
+/// let matches = PlayerSearch::new("Stutzle").matches(&players);
+/// assert_eq!(matches[0].item.id, 8482116);
+///
+/// let matches = PlayerSearch::new("alex ovechkin").position("F").matches(&players);
+/// assert_eq!(matches[0].item.id, 8471214);
+///
+/// assert!(PlayerSearch::new("Stützle").birth_date("1999").matches(&players).is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerSearch {
+    name: String,
+    birth_date: Option<String>,
+    position: Option<String>,
+    active: Option<bool>,
+    min_score: f64,
+}
+
+impl PlayerSearch {
+    /// Creates a search for players named `name`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            birth_date: None,
+            position: None,
+            active: None,
+            min_score: DEFAULT_MIN_SCORE,
+        }
+    }
+
+    /// Only matches players born on a date, in a month or in a year, e.g. `2002-01-15`,
+    /// `2002-01` or `2002`.
+    pub fn birth_date(mut self, birth_date: &str) -> Self {
+        self.birth_date = Some(birth_date.to_string());
+        self
+    }
+
+    /// Only matches players playing a position: `C`, `L`, `R`, `D`, `G` or `F` for any forward.
+    pub fn position(mut self, position: &str) -> Self {
+        self.position = Some(position.to_uppercase());
+        self
+    }
+
+    /// Only matches active (or inactive) players.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Sets the lowest score of a match, from `0.0` to `1.0`. Defaults to `0.8`.
+    pub fn min_score(mut self, min_score: f64) -> Self {
+        self.min_score = min_score;
+        self
+    }
+
+    /// The players matching the search, best match first.
+    pub fn matches(&self, players: &[Player]) -> Vec<NameMatch<Player>> {
+        let mut matches = players
+            .iter()
+            .filter(|player| self.is_candidate(player))
+            .filter_map(|player| {
+                let score = names::match_score(&self.name, &player.full_name);
+                (score >= self.min_score).then(|| NameMatch {
+                    item: player.clone(),
+                    score,
+                })
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| b.item.is_active().cmp(&a.item.is_active()))
+                .then_with(|| a.item.id.cmp(&b.item.id))
+        });
+        matches
+    }
+
+    fn is_candidate(&self, player: &Player) -> bool {
+        let birth_date = self.birth_date.as_ref().is_none_or(|birth_date| {
+            player
+                .birth_date
+                .as_ref()
+                .is_some_and(|date| date.starts_with(birth_date.as_str()))
+        });
+        let position = self.position.as_ref().is_none_or(|position| {
+            player.position_code.as_deref().is_some_and(|code| {
+                code == position || (position == "F" && matches!(code, "C" | "L" | "R"))
+            })
+        });
+        let active = self
+            .active
+            .is_none_or(|active| player.is_active() == active);
+        birth_date && position && active
+    }
+}
+
+impl Client {
+    /// Get a list of every player in league history.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_players().await?;
+    ///
+    /// println!("Got {} players", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_players(&self) -> Result<Vec<Player>, u16> {
+        let url = format!("{}/{}/players", self.stats_base_url, self.language);
+        let result = get::<PlayerResponse>(&self.transport, url).await;
+        match result {
+            Ok(response) => Ok(response.data),
+            Err(status_code) => Err(status_code),
+        }
+    }
+
+    /// Get a player by an `id`.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_player_by_id(8478402).await?;
+    ///
+    /// println!("Player with the id of 8478402: {:?}", response);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_player_by_id(&self, id: i64) -> Result<Option<Player>, u16> {
+        let result = self.get_players().await?;
+        Ok(result
+            .into_iter()
+            .filter(|player| player.id == id)
+            .collect::<Vec<_>>()
+            .first()
+            .cloned())
+    }
+
+    /// Search players by name with the NHL.com player search, returning at most `limit` results.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.search_players("Stützle", 10, Some(true)).await?;
+    ///
+    /// println!("Active players named 'Stützle': {:?}", response);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn search_players(
+        &self,
+        query: &str,
+        limit: i64,
+        active: Option<bool>,
+    ) -> Result<Vec<PlayerSearchResult>, u16> {
+        let mut params = vec![
+            ("culture", self.language.to_string()),
+            ("limit", limit.to_string()),
+            ("q", query.to_string()),
+        ];
+        if let Some(active) = active {
+            params.push(("active", active.to_string()));
+        }
+        let url = match Url::parse_with_params(&format!("{}/player", self.search_base_url), &params)
+        {
+            Ok(url) => url.to_string(),
+            Err(_) => return Err(StatusCode::BAD_REQUEST.as_u16()),
+        };
+        get::<Vec<PlayerSearchResult>>(&self.transport, url).await
+    }
+
+    /// Find players matching a fuzzy [`PlayerSearch`], best match first.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, PlayerSearch};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let search = PlayerSearch::new("Sebastian Aho").position("C");
+    /// let response = client.find_players(&search).await?;
+    ///
+    /// for found in response {
+    ///     println!("{} ({:?}): {}", found.item.full_name, found.item.birth_date, found.score);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn find_players(&self, search: &PlayerSearch) -> Result<Vec<NameMatch<Player>>, u16> {
+        let players = self.get_players().await?;
+        Ok(search.matches(&players))
+    }
+}
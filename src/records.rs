@@ -0,0 +1,394 @@
+use reqwest::{StatusCode, Url};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    game::GameType,
+    http::get,
+    ids::{SeasonId, TeamId},
+    stats::{SortDirection, StatsResponse},
+    Client,
+};
+
+/// The regular season or playoff record of a franchise in a season.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FranchiseSeasonResult {
+    pub id: i64,
+    pub franchise_id: i64,
+    pub season_id: SeasonId,
+    pub game_type_id: GameType,
+    pub team_id: Option<TeamId>,
+    pub team_name: Option<String>,
+    pub tri_code: Option<String>,
+    pub games_played: i64,
+    pub wins: i64,
+    pub losses: i64,
+    pub ties: Option<i64>,
+    pub overtime_losses: Option<i64>,
+    pub points: Option<i64>,
+    pub goals: Option<i64>,
+    pub goals_against: Option<i64>,
+    /// How the season ended, e.g. the playoff round the team was eliminated in.
+    pub decision: Option<String>,
+}
+
+/// The all-time totals of a team of a franchise, for a game type.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FranchiseTeamTotals {
+    pub id: i64,
+    pub franchise_id: i64,
+    pub team_id: TeamId,
+    pub team_name: String,
+    pub tri_code: String,
+    pub game_type_id: GameType,
+    pub active_franchise: i64,
+    pub first_season_id: SeasonId,
+    pub last_season_id: Option<SeasonId>,
+    pub games_played: i64,
+    pub wins: i64,
+    pub losses: i64,
+    pub ties: Option<i64>,
+    pub overtime_losses: Option<i64>,
+    pub shootout_wins: Option<i64>,
+    pub shootout_losses: Option<i64>,
+    pub home_wins: Option<i64>,
+    pub home_losses: Option<i64>,
+    pub home_ties: Option<i64>,
+    pub home_overtime_losses: Option<i64>,
+    pub road_wins: Option<i64>,
+    pub road_losses: Option<i64>,
+    pub road_ties: Option<i64>,
+    pub road_overtime_losses: Option<i64>,
+    pub goals_for: i64,
+    pub goals_against: i64,
+    pub penalty_minutes: Option<i64>,
+    pub point_pctg: Option<f64>,
+    pub points: Option<i64>,
+    pub shutouts: Option<i64>,
+}
+
+/// The career record of a goalie with a franchise.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FranchiseGoalieRecord {
+    pub id: i64,
+    pub franchise_id: i64,
+    pub franchise_name: String,
+    pub player_id: i64,
+    pub first_name: String,
+    pub last_name: String,
+    pub active_player: bool,
+    pub game_type_id: GameType,
+    pub games_played: i64,
+    pub seasons: i64,
+    pub wins: i64,
+    pub losses: i64,
+    pub ties: Option<i64>,
+    pub overtime_losses: Option<i64>,
+    pub shutouts: i64,
+    pub most_goals_against_one_game: Option<i64>,
+    pub most_saves_one_game: Option<i64>,
+    pub most_shots_against_one_game: Option<i64>,
+    pub most_shutouts_one_season: Option<i64>,
+    pub most_wins_one_season: Option<i64>,
+    pub rookie_games_played: Option<i64>,
+    pub rookie_shutouts: Option<i64>,
+    pub rookie_wins: Option<i64>,
+}
+
+/// The career record of a skater with a franchise.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FranchiseSkaterRecord {
+    pub id: i64,
+    pub franchise_id: i64,
+    pub franchise_name: String,
+    pub player_id: i64,
+    pub first_name: String,
+    pub last_name: String,
+    pub active_player: bool,
+    pub position_code: String,
+    pub game_type_id: GameType,
+    pub games_played: i64,
+    pub seasons: i64,
+    pub goals: i64,
+    pub assists: i64,
+    pub points: i64,
+    pub penalty_minutes: i64,
+    pub most_goals_one_game: Option<i64>,
+    pub most_goals_one_season: Option<i64>,
+    pub most_assists_one_game: Option<i64>,
+    pub most_assists_one_season: Option<i64>,
+    pub most_points_one_game: Option<i64>,
+    pub most_points_one_season: Option<i64>,
+    pub most_penalty_mins_one_season: Option<i64>,
+    pub rookie_games_played: Option<i64>,
+    pub rookie_points: Option<i64>,
+}
+
+impl Client {
+    /// Gets the rows of a records API resource of a franchise, of every game type when
+    /// `game_type` is `None`.
+    async fn get_franchise_records<T: DeserializeOwned>(
+        &self,
+        resource: &str,
+        franchise_id: i64,
+        game_type: Option<GameType>,
+        sort: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<T>, u16> {
+        let mut cayenne_exp = format!("franchiseId={}", franchise_id);
+        if let Some(game_type) = game_type {
+            cayenne_exp.push_str(&format!(" and gameTypeId={}", game_type.id()));
+        }
+        let mut params = vec![("cayenneExp", cayenne_exp)];
+        if let Some(property) = sort {
+            let sort = serde_json::json!([{
+                "property": property,
+                "direction": SortDirection::Descending.code(),
+            }]);
+            params.push(("sort", sort.to_string()));
+        }
+        if let Some(limit) = limit {
+            params.push(("limit", limit.to_string()));
+        }
+        let url = match Url::parse_with_params(
+            &format!("{}/{}", self.records_base_url, resource),
+            &params,
+        ) {
+            Ok(url) => url.to_string(),
+            Err(_) => return Err(StatusCode::BAD_REQUEST.as_u16()),
+        };
+        let response = get::<StatsResponse<T>>(&self.transport, url).await?;
+        Ok(response.data)
+    }
+
+    /// Get the season by season results of a franchise.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameType};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client
+    ///     .get_franchise_season_results(6, Some(GameType::RegularSeason))
+    ///     .await?;
+    ///
+    /// for season in response {
+    ///     println!("{}: {} points", season.season_id, season.points.unwrap_or_default());
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_franchise_season_results(
+        &self,
+        franchise_id: i64,
+        game_type: Option<GameType>,
+    ) -> Result<Vec<FranchiseSeasonResult>, u16> {
+        self.get_franchise_records(
+            "franchise-season-results",
+            franchise_id,
+            game_type,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Get the all-time totals of every team of a franchise.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_franchise_team_totals(27, None).await?;
+    ///
+    /// for totals in response {
+    ///     println!("{} ({:?}): {} wins", totals.team_name, totals.game_type_id, totals.wins);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_franchise_team_totals(
+        &self,
+        franchise_id: i64,
+        game_type: Option<GameType>,
+    ) -> Result<Vec<FranchiseTeamTotals>, u16> {
+        self.get_franchise_records("franchise-team-totals", franchise_id, game_type, None, None)
+            .await
+    }
+
+    /// Get the career records of every goalie of a franchise.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameType};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client
+    ///     .get_franchise_goalie_records(1, Some(GameType::RegularSeason))
+    ///     .await?;
+    ///
+    /// println!("The Canadiens have had {} goalies", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_franchise_goalie_records(
+        &self,
+        franchise_id: i64,
+        game_type: Option<GameType>,
+    ) -> Result<Vec<FranchiseGoalieRecord>, u16> {
+        self.get_franchise_records(
+            "franchise-goalie-records",
+            franchise_id,
+            game_type,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Get the career records of every skater of a franchise.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameType};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client
+    ///     .get_franchise_skater_records(1, Some(GameType::Playoffs))
+    ///     .await?;
+    ///
+    /// println!("{} Canadiens skaters have played in the playoffs", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_franchise_skater_records(
+        &self,
+        franchise_id: i64,
+        game_type: Option<GameType>,
+    ) -> Result<Vec<FranchiseSkaterRecord>, u16> {
+        self.get_franchise_records(
+            "franchise-skater-records",
+            franchise_id,
+            game_type,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Get the all-time skater leaders of a franchise in a `stat` of [`FranchiseSkaterRecord`],
+    /// e.g. `goals` or `points`.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameType};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client
+    ///     .get_franchise_skater_leaders(6, GameType::RegularSeason, "points", 10)
+    ///     .await?;
+    ///
+    /// for (rank, leader) in response.iter().enumerate() {
+    ///     println!("{}. {} {}: {}", rank + 1, leader.first_name, leader.last_name, leader.points);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_franchise_skater_leaders(
+        &self,
+        franchise_id: i64,
+        game_type: GameType,
+        stat: &str,
+        limit: i64,
+    ) -> Result<Vec<FranchiseSkaterRecord>, u16> {
+        self.get_franchise_records(
+            "franchise-skater-records",
+            franchise_id,
+            Some(game_type),
+            Some(stat),
+            Some(limit),
+        )
+        .await
+    }
+
+    /// Get the all-time goalie leaders of a franchise in a `stat` of [`FranchiseGoalieRecord`],
+    /// e.g. `wins` or `shutouts`.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameType};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client
+    ///     .get_franchise_goalie_leaders(6, GameType::RegularSeason, "wins", 5)
+    ///     .await?;
+    ///
+    /// for leader in response {
+    ///     println!("{} {}: {}", leader.first_name, leader.last_name, leader.wins);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_franchise_goalie_leaders(
+        &self,
+        franchise_id: i64,
+        game_type: GameType,
+        stat: &str,
+        limit: i64,
+    ) -> Result<Vec<FranchiseGoalieRecord>, u16> {
+        self.get_franchise_records(
+            "franchise-goalie-records",
+            franchise_id,
+            Some(game_type),
+            Some(stat),
+            Some(limit),
+        )
+        .await
+    }
+}
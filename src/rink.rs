@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::{Play, PlayByPlayResponse};
+
+/// Distance (in feet) from centre ice to either end board.
+pub const HALF_LENGTH: f64 = 100.0;
+/// Distance (in feet) from the centre line to either side board.
+pub const HALF_WIDTH: f64 = 42.5;
+/// Distance (in feet) from centre ice to the goal line.
+pub const GOAL_LINE_X: f64 = 89.0;
+/// Distance (in feet) from centre ice to the blue lines.
+pub const BLUE_LINE_X: f64 = 25.0;
+/// Distance (in feet) from centre ice to the top of the offensive faceoff circles.
+pub const TOP_OF_CIRCLES_X: f64 = 54.0;
+/// Distance (in feet) from the centre line to the offensive faceoff dots.
+pub const FACEOFF_DOT_Y: f64 = 22.0;
+/// Depth (in feet) of the point, measured from the blue line into the offensive zone.
+pub const POINT_DEPTH: f64 = 10.0;
+
+/// A zone of the rink, relative to the team that owns an event.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Zone {
+    Offensive,
+    #[default]
+    Neutral,
+    Defensive,
+}
+
+/// A location on the rink, in feet from centre ice, normalized so the owning team attacks the
+/// net at `x = GOAL_LINE_X`.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{RinkPoint, Zone};
+///
+/// let point = RinkPoint::new(79.0, 0.0);
+///
+/// assert_eq!(point.distance_to_net(), 10.0);
+/// assert_eq!(point.angle_to_net(), 0.0);
+/// assert_eq!(point.zone(), Zone::Offensive);
+/// assert!(point.is_slot());
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RinkPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl RinkPoint {
+    /// Creates a new, already normalized, [`RinkPoint`].
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Normalizes raw play-by-play coordinates.
+    ///
+    /// `attacks_right` is whether the owning team attacks the net at positive `x` in the play's
+    /// period. Coordinates are mirrored through centre ice when it does not.
+    pub fn from_raw(x: f64, y: f64, attacks_right: bool) -> Self {
+        if attacks_right {
+            Self::new(x, y)
+        } else {
+            Self::new(-x, -y)
+        }
+    }
+
+    /// Normalizes the location of a play from its event owner's point of view.
+    ///
+    /// The attacking direction comes from the play's `home_team_defending_side`, which flips
+    /// every period. When it is missing, the owner is assumed to attack the nearest net.
+    /// Returns `None` for plays without coordinates or an owning team.
+    pub fn from_play(play: &Play, home_team_id: i64) -> Option<Self> {
+        let details = play.details.as_ref()?;
+        let team_id = details.event_owner_team_id?;
+        let x = details.x_coord? as f64;
+        let y = details.y_coord? as f64;
+        let is_home = team_id == home_team_id;
+
+        let attacks_right = match play.home_team_defending_side.as_deref() {
+            Some("left") => is_home,
+            Some("right") => !is_home,
+            _ => x >= 0.0,
+        };
+
+        Some(Self::from_raw(x, y, attacks_right))
+    }
+
+    /// Distance (in feet) to the centre of the attacked net.
+    pub fn distance_to_net(&self) -> f64 {
+        (GOAL_LINE_X - self.x).hypot(self.y)
+    }
+
+    /// Angle (in degrees) off the line running through the centre of the attacked net.
+    ///
+    /// `0` is straight on, `90` is level with the goal line and anything above is behind the net.
+    pub fn angle_to_net(&self) -> f64 {
+        self.y.abs().atan2(GOAL_LINE_X - self.x).to_degrees()
+    }
+
+    /// The zone the point lies in.
+    pub fn zone(&self) -> Zone {
+        if self.x > BLUE_LINE_X {
+            Zone::Offensive
+        } else if self.x < -BLUE_LINE_X {
+            Zone::Defensive
+        } else {
+            Zone::Neutral
+        }
+    }
+
+    /// Whether the point is in the slot: between the faceoff dots, from the top of the circles
+    /// down to the goal line.
+    pub fn is_slot(&self) -> bool {
+        (TOP_OF_CIRCLES_X..=GOAL_LINE_X).contains(&self.x) && self.y.abs() <= FACEOFF_DOT_Y
+    }
+
+    /// Whether the point is at the point: the strip just inside the offensive blue line.
+    pub fn is_point(&self) -> bool {
+        self.x > BLUE_LINE_X && self.x <= BLUE_LINE_X + POINT_DEPTH
+    }
+}
+
+/// A play whose location has been normalized with [`RinkPoint::from_play`].
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizedEvent {
+    pub event_id: i64,
+    pub period: i64,
+    pub team_id: i64,
+    pub type_desc_key: String,
+    pub point: RinkPoint,
+}
+
+/// Normalizes every play with a location so that every event owner attacks the same net.
+///
+/// # Example
+/// ```no_run
+/// use nhl_rs::{normalize_plays, ClientBuilder, GameId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), u16> {
+/// let client = ClientBuilder::new().build();
+///
+/// let id = GameId::new(2023020204).unwrap();
+/// let response = client.get_play_by_play(id).await?;
+/// let slot_shots = normalize_plays(&response)
+///     .into_iter()
+///     .filter(|event| event.type_desc_key == "shot-on-goal" && event.point.is_slot())
+///     .count();
+///
+/// println!("{} shots on goal came from the slot", slot_shots);
+///
+/// Ok(())
+/// # }
+/// ```
+pub fn normalize_plays(play_by_play: &PlayByPlayResponse) -> Vec<NormalizedEvent> {
+    play_by_play
+        .plays
+        .iter()
+        .filter_map(|play| {
+            Some(NormalizedEvent {
+                event_id: play.event_id,
+                period: play.period_descriptor.number,
+                team_id: play.team_id()?,
+                type_desc_key: play.type_desc_key.clone(),
+                point: RinkPoint::from_play(play, play_by_play.home_team.id)?,
+            })
+        })
+        .collect()
+}
+
+/// A grid of weighted counts over the whole rink, used to build shot heat maps.
+///
+/// Cells are `bin_size` feet square, indexed from the defensive end boards (`column` 0) and the
+/// bottom side boards (`row` 0). Points outside of the rink are clamped to the nearest cell.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{HeatMap, RinkPoint};
+///
+/// let mut heat_map = HeatMap::new(10.0);
+/// heat_map.add(RinkPoint::new(80.0, 2.0), 1.0);
+/// heat_map.add(RinkPoint::new(81.0, 3.0), 0.5);
+///
+/// let (column, row) = heat_map.cell_of(RinkPoint::new(80.0, 2.0));
+/// assert_eq!(heat_map.get(column, row), Some(1.5));
+/// assert_eq!(heat_map.get(heat_map.columns, row), None);
+/// assert_eq!(heat_map.total(), 1.5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatMap {
+    pub bin_size: f64,
+    pub columns: usize,
+    pub rows: usize,
+    /// Row-major cell values.
+    pub cells: Vec<f64>,
+}
+
+impl HeatMap {
+    /// Creates an empty [`HeatMap`] with cells `bin_size` feet square.
+    ///
+    /// # Panics
+    /// Panics if `bin_size` is not positive.
+    pub fn new(bin_size: f64) -> Self {
+        assert!(bin_size > 0.0, "bin_size must be positive");
+        let columns = (2.0 * HALF_LENGTH / bin_size).ceil() as usize;
+        let rows = (2.0 * HALF_WIDTH / bin_size).ceil() as usize;
+        Self {
+            bin_size,
+            columns,
+            rows,
+            cells: vec![0.0; columns * rows],
+        }
+    }
+
+    /// Creates a [`HeatMap`] counting each point once.
+    pub fn from_points<I>(points: I, bin_size: f64) -> Self
+    where
+        I: IntoIterator<Item = RinkPoint>,
+    {
+        let mut heat_map = Self::new(bin_size);
+        for point in points {
+            heat_map.add(point, 1.0);
+        }
+        heat_map
+    }
+
+    /// The `(column, row)` of the cell containing a point.
+    pub fn cell_of(&self, point: RinkPoint) -> (usize, usize) {
+        let column = ((point.x + HALF_LENGTH) / self.bin_size).floor().max(0.0) as usize;
+        let row = ((point.y + HALF_WIDTH) / self.bin_size).floor().max(0.0) as usize;
+        (
+            column.min(self.columns.saturating_sub(1)),
+            row.min(self.rows.saturating_sub(1)),
+        )
+    }
+
+    /// Adds `weight` (e.g. `1.0` for a count or a shot's xG) to the cell containing a point.
+    ///
+    /// A heat map without cells, e.g. deserialized from an empty grid, is left unchanged.
+    pub fn add(&mut self, point: RinkPoint, weight: f64) {
+        let (column, row) = self.cell_of(point);
+        if let Some(cell) = self.cells.get_mut(row * self.columns + column) {
+            *cell += weight;
+        }
+    }
+
+    /// The value of a cell, or `None` if the cell is outside of the grid.
+    pub fn get(&self, column: usize, row: usize) -> Option<f64> {
+        if column >= self.columns || row >= self.rows {
+            return None;
+        }
+        self.cells.get(row * self.columns + column).copied()
+    }
+
+    /// The sum of every cell.
+    pub fn total(&self) -> f64 {
+        self.cells.iter().sum()
+    }
+
+    /// The value of the largest cell.
+    pub fn max(&self) -> f64 {
+        self.cells.iter().copied().fold(0.0, f64::max)
+    }
+}
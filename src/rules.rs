@@ -0,0 +1,212 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{Game, GameType},
+    ids::{SeasonId, TeamId},
+    standings::{PointsRuleset, Tiebreaker},
+};
+
+/// Number of periods in regulation time.
+pub const REGULATION_PERIODS: i64 = 3;
+/// The `period` reported for regular season games decided by a shootout.
+pub const SHOOTOUT_PERIOD: i64 = 5;
+
+/// How regular season games tied after regulation are played.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OvertimeFormat {
+    /// Games tied after regulation end in a tie.
+    #[default]
+    None,
+    /// A full, non sudden death, ten minute period (until 1942).
+    TenMinutes,
+    /// Five minutes of five on five sudden death (1983 to 1999).
+    FiveOnFive,
+    /// Five minutes of four on four sudden death (1999 to 2015).
+    FourOnFour,
+    /// Five minutes of three on three sudden death (since 2015).
+    ThreeOnThree,
+}
+
+/// The result of a game for one of its teams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GameResult {
+    RegulationWin,
+    OvertimeWin,
+    ShootoutWin,
+    Loss,
+    OvertimeLoss,
+    ShootoutLoss,
+    Tie,
+}
+
+impl GameResult {
+    /// The abbreviation used in standings (`W`, `L`, `T`, `OTL` or `SOL`).
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            GameResult::RegulationWin | GameResult::OvertimeWin | GameResult::ShootoutWin => "W",
+            GameResult::Loss => "L",
+            GameResult::OvertimeLoss => "OTL",
+            GameResult::ShootoutLoss => "SOL",
+            GameResult::Tie => "T",
+        }
+    }
+
+    pub fn is_win(&self) -> bool {
+        matches!(
+            self,
+            GameResult::RegulationWin | GameResult::OvertimeWin | GameResult::ShootoutWin
+        )
+    }
+}
+
+/// The rules of the NHL during a season.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{GameResult, OvertimeFormat, RuleSet, SeasonId};
+///
+/// let rules = RuleSet::for_season("1993-94".parse().unwrap());
+/// assert!(rules.ties);
+/// assert_eq!(rules.overtime, OvertimeFormat::FiveOnFive);
+/// assert_eq!(rules.points_for(GameResult::OvertimeLoss), 0);
+///
+/// let rules = RuleSet::for_season(SeasonId::new(20222023).unwrap());
+/// assert!(rules.shootout);
+/// assert_eq!(rules.overtime, OvertimeFormat::ThreeOnThree);
+/// assert_eq!(rules.points_for(GameResult::ShootoutLoss), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleSet {
+    /// The season the rules apply to.
+    pub season: SeasonId,
+    /// Whether regular season games can end in a tie.
+    pub ties: bool,
+    pub overtime: OvertimeFormat,
+    /// Length of regular season overtime in minutes, `0` when there is no overtime.
+    pub overtime_minutes: i64,
+    /// Whether regular season games tied after overtime are decided by a shootout.
+    pub shootout: bool,
+    pub points: PointsRuleset,
+}
+
+impl RuleSet {
+    /// The rules in effect for a `season` as seen in `Game.season`.
+    pub fn for_season(season: SeasonId) -> Self {
+        let start_year = season.start_year();
+
+        let (ties, overtime, overtime_minutes, shootout) = match start_year {
+            ..=1941 => (true, OvertimeFormat::TenMinutes, 10, false),
+            1942..=1982 => (true, OvertimeFormat::None, 0, false),
+            1983..=1998 => (true, OvertimeFormat::FiveOnFive, 5, false),
+            1999..=2004 => (true, OvertimeFormat::FourOnFour, 5, false),
+            2005..=2014 => (false, OvertimeFormat::FourOnFour, 5, true),
+            _ => (false, OvertimeFormat::ThreeOnThree, 5, true),
+        };
+
+        let points = match start_year {
+            ..=1998 => PointsRuleset::ties_era(),
+            1999..=2004 => PointsRuleset::overtime_loss_era(),
+            2005..=2009 => PointsRuleset {
+                tiebreakers: vec![
+                    Tiebreaker::GamesPlayed,
+                    Tiebreaker::Wins,
+                    Tiebreaker::HeadToHeadPoints,
+                    Tiebreaker::GoalDifferential,
+                ],
+                ..PointsRuleset::shootout_era()
+            },
+            2010..=2018 => PointsRuleset {
+                tiebreakers: vec![
+                    Tiebreaker::GamesPlayed,
+                    Tiebreaker::RegulationAndOvertimeWins,
+                    Tiebreaker::HeadToHeadPoints,
+                    Tiebreaker::GoalDifferential,
+                ],
+                ..PointsRuleset::shootout_era()
+            },
+            _ => PointsRuleset::shootout_era(),
+        };
+
+        Self {
+            season,
+            ties,
+            overtime,
+            overtime_minutes,
+            shootout,
+            points,
+        }
+    }
+
+    /// The result of a completed game for `team_id`.
+    ///
+    /// Overtime and shootouts are derived from the game's last `period`, games without one are
+    /// treated as decided in regulation. Losses in overtime are plain losses in seasons without a
+    /// point for an overtime loss. Returns `None` if the team did not play in the game.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{Game, GameResult, RuleSet, SeasonId, TeamId};
+    ///
+    /// let home = TeamId::new(1).unwrap();
+    /// let game = Game {
+    ///     home_team_id: home,
+    ///     visiting_team_id: TeamId::new(2).unwrap(),
+    ///     home_score: 2,
+    ///     visiting_score: 3,
+    ///     period: Some(4),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let rules = RuleSet::for_season(SeasonId::new(19931994).unwrap());
+    /// assert_eq!(rules.classify(&game, home), Some(GameResult::Loss));
+    ///
+    /// let rules = RuleSet::for_season(SeasonId::new(20002001).unwrap());
+    /// assert_eq!(rules.classify(&game, home), Some(GameResult::OvertimeLoss));
+    /// ```
+    pub fn classify(&self, game: &Game, team_id: TeamId) -> Option<GameResult> {
+        let (goals_for, goals_against) = if game.home_team_id == team_id {
+            (game.home_score, game.visiting_score)
+        } else if game.visiting_team_id == team_id {
+            (game.visiting_score, game.home_score)
+        } else {
+            return None;
+        };
+
+        let period = game.period.unwrap_or(REGULATION_PERIODS);
+        let extra_time = period > REGULATION_PERIODS;
+        let shootout =
+            self.shootout && game.game_type == GameType::RegularSeason && period >= SHOOTOUT_PERIOD;
+
+        Some(
+            match (goals_for.cmp(&goals_against), shootout, extra_time) {
+                (Ordering::Equal, _, _) => GameResult::Tie,
+                (Ordering::Greater, true, _) => GameResult::ShootoutWin,
+                (Ordering::Greater, false, true) => GameResult::OvertimeWin,
+                (Ordering::Greater, false, false) => GameResult::RegulationWin,
+                (Ordering::Less, true, _) => GameResult::ShootoutLoss,
+                (Ordering::Less, false, true) if self.points.overtime_loss > 0 => {
+                    GameResult::OvertimeLoss
+                }
+                (Ordering::Less, false, _) => GameResult::Loss,
+            },
+        )
+    }
+
+    /// The standings points awarded for a result.
+    pub fn points_for(&self, result: GameResult) -> i64 {
+        match result {
+            GameResult::RegulationWin | GameResult::OvertimeWin | GameResult::ShootoutWin => {
+                self.points.win
+            }
+            GameResult::Loss => 0,
+            GameResult::OvertimeLoss => self.points.overtime_loss,
+            GameResult::ShootoutLoss => self.points.shootout_loss,
+            GameResult::Tie => self.points.tie,
+        }
+    }
+}
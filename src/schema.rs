@@ -0,0 +1,530 @@
+use std::collections::BTreeMap;
+
+use serde::{
+    de::{
+        value::Error, DeserializeOwned, DeserializeSeed, Error as _, IntoDeserializer, MapAccess,
+        Visitor,
+    },
+    forward_to_deserialize_any, Deserialize, Deserializer, Serialize,
+};
+use serde_json::Value;
+
+use crate::{
+    country::Country,
+    draft::Draft,
+    franchise::Franchise,
+    game::{metadata::Property, Game, GameMetadataResponse},
+    glossary::GlossaryItem,
+    http::get,
+    league::{Conference, Division},
+    players::Player,
+    season::{ComponentSeason, Season},
+    team::Team,
+    Client,
+};
+
+/// A struct modelling a resource of the stats API, checked against the resource's `/meta`.
+pub trait SchemaModel: DeserializeOwned {
+    /// The name of the resource, e.g. `country`.
+    fn resource() -> &'static str;
+
+    /// The JSON type of each field, in declaration order, derived from the type the field
+    /// deserializes as. Optional fields have the type of their value.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{Country, FieldType, SchemaModel};
+    ///
+    /// let fields = Country::fields();
+    ///
+    /// assert_eq!(fields[0], ("id".to_string(), FieldType::String));
+    /// assert!(fields.contains(&("imageUrl".to_string(), FieldType::String)));
+    /// assert!(fields.contains(&("isActive".to_string(), FieldType::Integer)));
+    /// ```
+    fn fields() -> Vec<(String, FieldType)> {
+        let mut names = vec![];
+        let _ = Self::deserialize(StructProbe {
+            names: &mut names,
+            field: None,
+        });
+
+        names
+            .into_iter()
+            .map(|name| {
+                let mut field_type = FieldType::Unknown;
+                let _ = Self::deserialize(StructProbe {
+                    names: &mut vec![],
+                    field: Some((name, &mut field_type)),
+                });
+                (name.to_string(), field_type)
+            })
+            .collect()
+    }
+}
+
+/// A deserializer recording the field names of a struct and, given a field, the type that field
+/// deserializes as. Deserialization always stops with an error once recorded.
+struct StructProbe<'a> {
+    names: &'a mut Vec<&'static str>,
+    field: Option<(&'static str, &'a mut FieldType)>,
+}
+
+impl<'de> Deserializer<'de> for StructProbe<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+        Err(Error::custom("not a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.names.extend(fields);
+        match self.field {
+            Some((name, field_type)) => visitor.visit_map(FieldProbe {
+                name: Some(name),
+                field_type,
+            }),
+            None => Err(Error::custom("fields recorded")),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf option unit
+        unit_struct newtype_struct seq tuple tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// A map of a single struct field, whose value records the type it deserializes as.
+struct FieldProbe<'a> {
+    name: Option<&'static str>,
+    field_type: &'a mut FieldType,
+}
+
+impl<'de> MapAccess<'de> for FieldProbe<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.name.take() {
+            Some(name) => seed.deserialize(name.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(TypeProbe(self.field_type))
+    }
+}
+
+/// A deserializer recording the type a value deserializes as, looking through options and
+/// newtypes.
+struct TypeProbe<'a>(&'a mut FieldType);
+
+macro_rules! probe_types {
+    ($($method:ident => $field_type:ident,)+) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+                *self.0 = FieldType::$field_type;
+                Err(Error::custom("type recorded"))
+            }
+        )+
+    };
+}
+
+impl<'de> Deserializer<'de> for TypeProbe<'_> {
+    type Error = Error;
+
+    probe_types! {
+        deserialize_any => Unknown,
+        deserialize_bool => Boolean,
+        deserialize_i8 => Integer,
+        deserialize_i16 => Integer,
+        deserialize_i32 => Integer,
+        deserialize_i64 => Integer,
+        deserialize_u8 => Integer,
+        deserialize_u16 => Integer,
+        deserialize_u32 => Integer,
+        deserialize_u64 => Integer,
+        deserialize_f32 => Number,
+        deserialize_f64 => Number,
+        deserialize_char => String,
+        deserialize_str => String,
+        deserialize_string => String,
+        deserialize_bytes => Array,
+        deserialize_byte_buf => Array,
+        deserialize_unit => Unknown,
+        deserialize_seq => Array,
+        deserialize_map => Object,
+        deserialize_identifier => String,
+        deserialize_ignored_any => Unknown,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_map(visitor)
+    }
+
+    /// Enums of the API are serialized as their variant name.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_string(visitor)
+    }
+}
+
+macro_rules! schema_models {
+    ($($model:ty => $resource:literal,)+) => {
+        $(
+            impl SchemaModel for $model {
+                fn resource() -> &'static str {
+                    $resource
+                }
+            }
+        )+
+    };
+}
+
+schema_models! {
+    ComponentSeason => "componentSeason",
+    Conference => "conference",
+    Country => "country",
+    Division => "division",
+    Draft => "draft",
+    Franchise => "franchise",
+    Game => "game",
+    GlossaryItem => "glossary",
+    Player => "players",
+    Season => "season",
+    Team => "team",
+}
+
+/// The JSON type of a field.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FieldType {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Array,
+    Object,
+    /// The type isn't known, e.g. for a field holding any JSON value.
+    #[default]
+    Unknown,
+}
+
+impl FieldType {
+    /// The type of a JSON value.
+    pub fn of(value: &Value) -> Self {
+        match value {
+            Value::String(_) => Self::String,
+            Value::Number(number) if number.is_f64() => Self::Number,
+            Value::Number(_) => Self::Integer,
+            Value::Bool(_) => Self::Boolean,
+            Value::Array(_) => Self::Array,
+            Value::Object(_) => Self::Object,
+            Value::Null => Self::Unknown,
+        }
+    }
+
+    /// The type of a `/meta` property, from its `type` and `format`.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::FieldType;
+    ///
+    /// assert_eq!(FieldType::from_metadata("integer", Some("int64")), FieldType::Integer);
+    /// assert_eq!(FieldType::from_metadata("string", Some("date-time")), FieldType::String);
+    /// assert_eq!(FieldType::from_metadata("number", Some("double")), FieldType::Number);
+    /// ```
+    pub fn from_metadata(type_field: &str, format: Option<&str>) -> Self {
+        let format = format.unwrap_or_default().to_lowercase();
+        match type_field.to_lowercase().as_str() {
+            "string" | "char" | "date" | "datetime" | "timestamp" | "time" => Self::String,
+            "integer" | "int" | "long" | "short" | "byte" => Self::Integer,
+            "number" if matches!(format.as_str(), "int32" | "int64") => Self::Integer,
+            "number" | "double" | "float" | "decimal" | "bigdecimal" => Self::Number,
+            "boolean" | "bool" => Self::Boolean,
+            "array" | "list" => Self::Array,
+            "object" | "map" => Self::Object,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Whether a field of this type can hold values of `other`. Unknown types match any type,
+    /// and numbers hold integers.
+    pub fn accepts(&self, other: &FieldType) -> bool {
+        self == other
+            || *self == Self::Unknown
+            || *other == Self::Unknown
+            || (*self == Self::Number && *other == Self::Integer)
+    }
+}
+
+/// A field typed differently by a struct and the API.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetypedField {
+    pub name: String,
+    /// The type of the struct field.
+    pub expected: FieldType,
+    /// The type declared by `/meta`.
+    pub actual: FieldType,
+}
+
+/// The differences between a struct and the `/meta` of its resource.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{Country, FieldType, GameMetadataResponse, SchemaDrift};
+///
+/// let metadata: GameMetadataResponse = serde_json::from_str(
+///     r#"{
+///         "entity": {
+///             "name": "Country",
+///             "properties": [
+///                 {"name": "id", "type": "string"},
+///                 {"name": "country3Code", "type": "string"},
+///                 {"name": "countryCode", "type": "string"},
+///                 {"name": "countryName", "type": "string"},
+///                 {"name": "hasPlayerStats", "type": "boolean"},
+///                 {"name": "imageUrl", "type": "string"},
+///                 {"name": "iocCode", "type": "string"},
+///                 {"name": "isActive", "type": "integer"},
+///                 {"name": "nationalityName", "type": "string"},
+///                 {"name": "olympicUrl", "type": "string"},
+///                 {"name": "population", "type": "integer"}
+///             ]
+///         },
+///         "links": []
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// let drift = SchemaDrift::check::<Country>(&metadata);
+///
+/// assert_eq!(drift.new_fields[0].name, "population");
+/// assert_eq!(drift.missing_fields, vec!["thumbnailUrl"]);
+/// assert_eq!(drift.retyped_fields[0].name, "hasPlayerStats");
+/// assert_eq!(drift.retyped_fields[0].actual, FieldType::Boolean);
+/// assert!(!drift.is_empty());
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDrift {
+    pub resource: String,
+    /// Properties of the API missing from the struct.
+    pub new_fields: Vec<Property>,
+    /// Fields of the struct no longer declared by the API.
+    pub missing_fields: Vec<String>,
+    pub retyped_fields: Vec<RetypedField>,
+}
+
+impl SchemaDrift {
+    /// Compares the fields of `T` with the properties declared by its `/meta`.
+    ///
+    /// Relationship properties, e.g. `franchise`, match `franchiseId` fields.
+    pub fn check<T: SchemaModel>(metadata: &GameMetadataResponse) -> Self {
+        let fields = T::fields();
+        let properties = &metadata.entity.properties;
+        let field_of = |property: &Property| {
+            fields.iter().find(|(name, _)| {
+                *name == property.name
+                    || (property.relationship == Some(true)
+                        && *name == format!("{}Id", property.name))
+            })
+        };
+
+        let mut drift = SchemaDrift {
+            resource: T::resource().to_string(),
+            ..Default::default()
+        };
+        for property in properties {
+            match field_of(property) {
+                Some((name, expected)) => {
+                    let actual =
+                        FieldType::from_metadata(&property.type_field, property.format.as_deref());
+                    if property.relationship != Some(true) && !expected.accepts(&actual) {
+                        drift.retyped_fields.push(RetypedField {
+                            name: name.clone(),
+                            expected: expected.clone(),
+                            actual,
+                        });
+                    }
+                }
+                None => drift.new_fields.push(property.clone()),
+            }
+        }
+        for (name, _) in &fields {
+            if !properties.iter().any(|property| {
+                field_of(property).is_some_and(|(field_name, _)| field_name == name)
+            }) {
+                drift.missing_fields.push(name.clone());
+            }
+        }
+        drift.missing_fields.sort();
+        drift
+    }
+
+    /// Whether the struct matches the API.
+    pub fn is_empty(&self) -> bool {
+        self.new_fields.is_empty()
+            && self.missing_fields.is_empty()
+            && self.retyped_fields.is_empty()
+    }
+}
+
+impl Client {
+    /// Get the `/meta` of a stats resource, e.g. `country`.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_metadata("franchise").await?;
+    ///
+    /// println!("Got {:?} franchise properties", response.entity.properties.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_metadata(&self, resource: &str) -> Result<GameMetadataResponse, u16> {
+        let url = format!(
+            "{}/{}/{}/meta",
+            self.stats_base_url, self.language, resource
+        );
+        get::<GameMetadataResponse>(&self.transport, url).await
+    }
+
+    /// Compares a struct with the `/meta` of its resource.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, Team};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.check_schema::<Team>().await?;
+    ///
+    /// println!("Team drift: {:?}", response);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn check_schema<T: SchemaModel>(&self) -> Result<SchemaDrift, u16> {
+        let metadata = self.get_metadata(T::resource()).await?;
+        Ok(SchemaDrift::check::<T>(&metadata))
+    }
+
+    /// Compares every supported stats resource with its `/meta`, returning the resources that
+    /// drifted or whose `/meta` could not be fetched, by name.
+    ///
+    /// A resource failing doesn't stop the others from being checked; its HTTP error code is
+    /// returned in its place.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// for (resource, result) in client.check_all_schemas().await {
+    ///     match result {
+    ///         Ok(drift) => println!("{}: {:?}", resource, drift),
+    ///         Err(status_code) => println!("{}: failed with {}", resource, status_code),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn check_all_schemas(&self) -> BTreeMap<&'static str, Result<SchemaDrift, u16>> {
+        let results = [
+            (
+                ComponentSeason::resource(),
+                self.check_schema::<ComponentSeason>().await,
+            ),
+            (
+                Conference::resource(),
+                self.check_schema::<Conference>().await,
+            ),
+            (Country::resource(), self.check_schema::<Country>().await),
+            (Division::resource(), self.check_schema::<Division>().await),
+            (Draft::resource(), self.check_schema::<Draft>().await),
+            (
+                Franchise::resource(),
+                self.check_schema::<Franchise>().await,
+            ),
+            (Game::resource(), self.check_schema::<Game>().await),
+            (
+                GlossaryItem::resource(),
+                self.check_schema::<GlossaryItem>().await,
+            ),
+            (Player::resource(), self.check_schema::<Player>().await),
+            (Season::resource(), self.check_schema::<Season>().await),
+            (Team::resource(), self.check_schema::<Team>().await),
+        ];
+        results
+            .into_iter()
+            .filter(|(_, result)| !matches!(result, Ok(drift) if drift.is_empty()))
+            .collect()
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{game::GameType, ids::SeasonId};
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ComponentSeasonResponse {
@@ -12,6 +14,6 @@ pub struct ComponentSeasonResponse {
 pub struct ComponentSeason {
     pub id: String,
     pub component: String,
-    pub game_type_id: i64,
-    pub season_id: i64,
+    pub game_type_id: GameType,
+    pub season_id: SeasonId,
 }
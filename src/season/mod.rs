@@ -1,8 +1,91 @@
 pub mod component_season;
+#[allow(clippy::module_inception)]
+pub mod season;
 
-use crate::{http::get, Client};
+use crate::{date, game::GameType, http::get, ids::SeasonId, Client};
 
-pub use crate::season::component_season::{ComponentSeason, ComponentSeasonResponse};
+pub use crate::season::{
+    component_season::{ComponentSeason, ComponentSeasonResponse},
+    season::{Season, SeasonResponse},
+};
+
+impl ComponentSeason {
+    /// Picks the component season in progress on a `YYYY-MM-DD` date.
+    ///
+    /// This is the latest season started by `date`: its playoffs once the regular season is
+    /// over, and its regular season otherwise. Without season dates, the latest component season
+    /// is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{ComponentSeason, GameType, Season, SeasonId};
+    ///
+    /// let season = Season {
+    ///     id: SeasonId::new(20232024).unwrap(),
+    ///     start_date: "2023-10-10T17:00:00".to_string(),
+    ///     regular_season_end_date: "2024-04-18T22:30:00".to_string(),
+    ///     end_date: "2024-06-24T00:00:00".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let components = [
+    ///     ComponentSeason {
+    ///         season_id: season.id,
+    ///         game_type_id: GameType::RegularSeason,
+    ///         ..Default::default()
+    ///     },
+    ///     ComponentSeason {
+    ///         season_id: season.id,
+    ///         game_type_id: GameType::Playoffs,
+    ///         ..Default::default()
+    ///     },
+    /// ];
+    ///
+    /// let current = ComponentSeason::current(&components, &[season.clone()], "2024-01-01");
+    /// assert_eq!(current.unwrap().game_type_id, GameType::RegularSeason);
+    ///
+    /// let current = ComponentSeason::current(&components, &[season], "2024-05-01");
+    /// assert_eq!(current.unwrap().game_type_id, GameType::Playoffs);
+    /// ```
+    pub fn current(
+        components: &[ComponentSeason],
+        seasons: &[Season],
+        date: &str,
+    ) -> Option<ComponentSeason> {
+        let latest = || {
+            components
+                .iter()
+                .max_by_key(|component| (component.season_id, component.game_type_id.id()))
+                .cloned()
+        };
+        let Some(season) = seasons
+            .iter()
+            .filter(|season| season.has_started(date))
+            .max_by_key(|season| season.id)
+        else {
+            return latest();
+        };
+
+        let game_type = if season.is_regular_season_over(date) {
+            GameType::Playoffs
+        } else {
+            GameType::RegularSeason
+        };
+        let of_season = components
+            .iter()
+            .filter(|component| component.season_id == season.id)
+            .collect::<Vec<_>>();
+        of_season
+            .iter()
+            .find(|component| component.game_type_id == game_type)
+            .or_else(|| {
+                of_season
+                    .iter()
+                    .find(|component| component.game_type_id == GameType::RegularSeason)
+            })
+            .map(|component| (*component).clone())
+            .or_else(latest)
+    }
+}
 
 impl Client {
     /// Get component season information.
@@ -17,18 +100,133 @@ impl Client {
     ///
     /// let response = client.get_component_season().await?;
     ///
-    /// println!("Current season ID {}", response[0].season_id);
+    /// println!("Got {} component seasons", response.len());
     ///
     /// Ok(())
     /// # }
     /// ```
     pub async fn get_component_season(&self) -> Result<Vec<ComponentSeason>, u16> {
         let url = format!("{}/{}/componentSeason", self.stats_base_url, self.language);
-        let result = get::<ComponentSeasonResponse>(url).await;
+        let result = get::<ComponentSeasonResponse>(&self.transport, url).await;
 
         match result {
             Ok(response) => Ok(response.data),
             Err(status_code) => Err(status_code),
         }
     }
+
+    /// Get the component season in progress today, e.g. the playoffs of the 2023-24 season.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.current_season().await?.unwrap();
+    ///
+    /// println!("Current season ID {}", response.season_id);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn current_season(&self) -> Result<Option<ComponentSeason>, u16> {
+        let components = self.get_component_season().await?;
+        let seasons = self.get_seasons().await?;
+        Ok(ComponentSeason::current(
+            &components,
+            &seasons,
+            &date::today(),
+        ))
+    }
+
+    /// Get a list of seasons with their dates and rules.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_seasons().await?;
+    ///
+    /// println!("The NHL has had {} seasons", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_seasons(&self) -> Result<Vec<Season>, u16> {
+        let url = format!("{}/{}/season", self.stats_base_url, self.language);
+        let result = get::<SeasonResponse>(&self.transport, url).await;
+        match result {
+            Ok(response) => Ok(response.data),
+            Err(status_code) => Err(status_code),
+        }
+    }
+
+    /// Get a season by an `id`.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, SeasonId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let id = SeasonId::new(20042005).unwrap();
+    /// let response = client.get_season_by_id(id).await?;
+    ///
+    /// println!("The 2004-05 season: {:?}", response);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_season_by_id(&self, id: SeasonId) -> Result<Option<Season>, u16> {
+        let result = self.get_seasons().await?;
+        Ok(result
+            .into_iter()
+            .filter(|season| season.id == id)
+            .collect::<Vec<_>>()
+            .first()
+            .cloned())
+    }
+
+    /// Get the ids of every season, oldest first, from the web API.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_season_ids().await?;
+    ///
+    /// println!("Latest season: {:?}", response.last());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_season_ids(&self) -> Result<Vec<SeasonId>, u16> {
+        let url = format!("{}/v1/season", self.base_url);
+        get::<Vec<SeasonId>>(&self.transport, url).await
+    }
 }
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ids::SeasonId, rules::RuleSet, standings::Tiebreaker};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonResponse {
+    pub data: Vec<Season>,
+    pub total: i64,
+}
+
+/// A season of the stats API. Flags are `1` when in use and `0` otherwise.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Season {
+    pub id: SeasonId,
+    /// e.g. `2023-24`.
+    pub formatted_season_id: String,
+    pub season_ordinal: i64,
+    pub preseason_startdate: Option<String>,
+    pub start_date: String,
+    pub regular_season_end_date: String,
+    /// The end of the season, including the playoffs.
+    pub end_date: String,
+    /// The number of regular season games of each team.
+    pub number_of_games: i64,
+    pub total_regular_season_games: Option<i64>,
+    pub total_playoff_games: Option<i64>,
+    pub ties_in_use: i64,
+    #[serde(rename = "pointForOTLossInUse")]
+    pub point_for_ot_loss_in_use: i64,
+    pub row_in_use: i64,
+    pub wildcard_in_use: i64,
+    pub conferences_in_use: i64,
+    pub divisions_in_use: i64,
+    pub all_star_game_in_use: i64,
+    pub entry_draft_in_use: i64,
+    pub olympics_participation: i64,
+}
+
+impl Season {
+    /// The rules of the season, including whether shootouts were used.
+    ///
+    /// Ties, the point for an overtime loss and the ROW tiebreaker follow the season's flags, the
+    /// rest follows [`RuleSet::for_season`].
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{GameResult, Season, SeasonId, Tiebreaker};
+    ///
+    /// let season = Season {
+    ///     id: SeasonId::new(19981999).unwrap(),
+    ///     ties_in_use: 1,
+    ///     point_for_ot_loss_in_use: 1,
+    ///     row_in_use: 1,
+    ///     ..Default::default()
+    /// };
+    /// let rules = season.rules();
+    ///
+    /// assert!(rules.ties);
+    /// assert_eq!(rules.points_for(GameResult::OvertimeLoss), 1);
+    /// assert!(rules
+    ///     .points
+    ///     .tiebreakers
+    ///     .contains(&Tiebreaker::RegulationAndOvertimeWins));
+    /// ```
+    pub fn rules(&self) -> RuleSet {
+        let mut rules = RuleSet::for_season(self.id);
+
+        rules.ties = self.ties_in_use == 1;
+        rules.points.tie = i64::from(rules.ties);
+        rules.points.overtime_loss = i64::from(self.point_for_ot_loss_in_use == 1);
+        if rules.shootout {
+            rules.points.shootout_loss = rules.points.overtime_loss;
+        }
+
+        let row = Tiebreaker::RegulationAndOvertimeWins;
+        let tiebreakers = &mut rules.points.tiebreakers;
+        if self.row_in_use != 1 {
+            tiebreakers.retain(|tiebreaker| *tiebreaker != row);
+        } else if !tiebreakers.contains(&row) {
+            let index = tiebreakers
+                .iter()
+                .position(|tiebreaker| *tiebreaker == Tiebreaker::Wins)
+                .unwrap_or(tiebreakers.len());
+            tiebreakers.insert(index, row);
+        }
+        rules
+    }
+
+    /// Whether the regular season is over on a `YYYY-MM-DD` date.
+    pub fn is_regular_season_over(&self, date: &str) -> bool {
+        date_of(&self.regular_season_end_date) < date
+    }
+
+    /// Whether the season, including the playoffs, has started on a `YYYY-MM-DD` date.
+    pub fn has_started(&self, date: &str) -> bool {
+        date_of(&self.start_date) <= date
+    }
+}
+
+/// The `YYYY-MM-DD` date of a date time.
+fn date_of(date_time: &str) -> &str {
+    date_time.get(..10).unwrap_or(date_time)
+}
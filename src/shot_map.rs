@@ -0,0 +1,422 @@
+use std::{collections::HashMap, fmt::Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    hash::fnv1a,
+    rink::{RinkPoint, BLUE_LINE_X, FACEOFF_DOT_Y, GOAL_LINE_X, HALF_LENGTH, HALF_WIDTH},
+    xg::GameXg,
+    HeatMap, NormalizedEvent,
+};
+
+/// Colours given to teams without an explicit colour, in order of first appearance.
+const TEAM_PALETTE: [&str; 6] = [
+    "#1f77b4", "#d62728", "#2ca02c", "#9467bd", "#ff7f0e", "#17becf",
+];
+/// Colour of the lowest xG markers.
+const LOW_XG_COLOR: (u8, u8, u8) = (49, 130, 189);
+/// Colour of markers at or above [`HIGH_XG`].
+const HIGH_XG_COLOR: (u8, u8, u8) = (222, 45, 38);
+/// The xG at which markers reach [`HIGH_XG_COLOR`].
+const HIGH_XG: f64 = 0.5;
+/// Radius (in feet) of the rounded rink corners.
+const CORNER_RADIUS: f64 = 28.0;
+/// Radius (in feet) of the centre ice and faceoff circles.
+const CIRCLE_RADIUS: f64 = 15.0;
+/// Radius (in feet) of the goal crease.
+const CREASE_RADIUS: f64 = 6.0;
+/// Distance (in feet) from centre ice to the offensive faceoff dots.
+const FACEOFF_DOT_X: f64 = GOAL_LINE_X - 20.0;
+/// Distance (in feet) from centre ice to the neutral zone faceoff dots.
+const NEUTRAL_DOT_X: f64 = 20.0;
+
+/// A single shot drawn on a [`ShotMap`].
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShotMarker {
+    pub point: RinkPoint,
+    pub team_id: i64,
+    pub is_goal: bool,
+    pub xg: Option<f64>,
+}
+
+impl From<&NormalizedEvent> for ShotMarker {
+    fn from(event: &NormalizedEvent) -> Self {
+        Self {
+            point: event.point,
+            team_id: event.team_id,
+            is_goal: event.type_desc_key == "goal",
+            xg: None,
+        }
+    }
+}
+
+/// How [`ShotMarker`]s are coloured.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MarkerColor {
+    /// One colour per team.
+    #[default]
+    Team,
+    /// A gradient from blue (low xG) to red (high xG).
+    Xg,
+}
+
+/// Renders a regulation NHL rink with shot markers and an optional heat map layer as SVG.
+///
+/// Points are expected to be normalized (see [`RinkPoint`]), so every team attacks the right
+/// hand net unless it is mirrored with [`ShotMap::mirror_team`].
+///
+/// # Example
+/// ```
+/// use nhl_rs::{HeatMap, MarkerColor, RinkPoint, ShotMap, ShotMarker};
+///
+/// let markers = vec![
+///     ShotMarker { point: RinkPoint::new(80.0, 3.0), team_id: 1, is_goal: true, xg: Some(0.3) },
+///     ShotMarker { point: RinkPoint::new(40.0, -20.0), team_id: 2, is_goal: false, xg: Some(0.02) },
+/// ];
+/// let heat_map = HeatMap::from_points(markers.iter().map(|marker| marker.point), 5.0);
+///
+/// let svg = ShotMap::new()
+///     .markers(markers)
+///     .heat_map(heat_map)
+///     .color_by(MarkerColor::Xg)
+///     .mirror_team(2)
+///     .render();
+///
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.ends_with("</svg>\n"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShotMap {
+    markers: Vec<ShotMarker>,
+    heat_map: Option<HeatMap>,
+    color_by: MarkerColor,
+    team_colors: HashMap<i64, String>,
+    mirrored_teams: Vec<i64>,
+    scale: f64,
+    id: Option<String>,
+}
+
+impl Default for ShotMap {
+    fn default() -> Self {
+        Self {
+            markers: Vec::new(),
+            heat_map: None,
+            color_by: MarkerColor::default(),
+            team_colors: HashMap::new(),
+            mirrored_teams: Vec::new(),
+            scale: 5.0,
+            id: None,
+        }
+    }
+}
+
+impl ShotMap {
+    /// Creates a new, empty, [`ShotMap`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`ShotMap`] of every shot scored in a game, coloured by xG.
+    pub fn from_game_xg(game: &GameXg) -> Self {
+        let markers = game
+            .shots
+            .iter()
+            .map(|shot| ShotMarker {
+                point: shot.point,
+                team_id: shot.team_id,
+                is_goal: shot.is_goal,
+                xg: Some(shot.xg),
+            })
+            .collect();
+        Self::new().markers(markers).color_by(MarkerColor::Xg)
+    }
+
+    /// Sets the shots to draw.
+    pub fn markers(mut self, markers: Vec<ShotMarker>) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Sets a heat map to draw underneath the shots.
+    pub fn heat_map(mut self, heat_map: HeatMap) -> Self {
+        self.heat_map = Some(heat_map);
+        self
+    }
+
+    /// Sets how markers are coloured.
+    pub fn color_by(mut self, color_by: MarkerColor) -> Self {
+        self.color_by = color_by;
+        self
+    }
+
+    /// Sets the colour (any SVG colour, e.g. `"#c8102e"`) of a team's markers. The colour is
+    /// XML-escaped when rendered.
+    pub fn team_color(mut self, team_id: i64, color: &str) -> Self {
+        self.team_colors.insert(team_id, color.to_string());
+        self
+    }
+
+    /// Draws a team's shots on the left hand side of the rink.
+    pub fn mirror_team(mut self, team_id: i64) -> Self {
+        self.mirrored_teams.push(team_id);
+        self
+    }
+
+    /// Sets the number of pixels per foot. Defaults to `5`.
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the prefix of the element ids of the SVG document, so several maps can be inlined in
+    /// one page. Defaults to a hash of the map's content.
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Renders the map as an SVG document.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::{RinkPoint, ShotMap, ShotMarker};
+    ///
+    /// let marker = ShotMarker {
+    ///     point: RinkPoint::new(80.0, 2.0),
+    ///     team_id: 1,
+    ///     ..Default::default()
+    /// };
+    /// let svg = ShotMap::new()
+    ///     .markers(vec![marker])
+    ///     .team_color(1, r#"red"/><script/>"#)
+    ///     .id("home")
+    ///     .render();
+    ///
+    /// assert!(svg.contains(r#"fill="red&quot;/&gt;&lt;script/&gt;""#));
+    /// assert!(svg.contains(r#"<clipPath id="home-boards">"#));
+    /// ```
+    pub fn render(&self) -> String {
+        let mut content = String::new();
+        self.render_rink(&mut content);
+        if let Some(heat_map) = &self.heat_map {
+            render_heat_map(&mut content, heat_map);
+        }
+        self.render_markers(&mut content);
+        let id = match &self.id {
+            Some(id) => escape(id),
+            None => format!("nhl-rs-{:016x}", fnv1a(content.as_bytes())),
+        };
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+            fmt(2.0 * HALF_LENGTH * self.scale),
+            fmt(2.0 * HALF_WIDTH * self.scale),
+            fmt(2.0 * HALF_LENGTH),
+            fmt(2.0 * HALF_WIDTH),
+        );
+        let _ = writeln!(
+            svg,
+            r#"<defs><clipPath id="{}-boards"><rect x="0" y="0" width="{}" height="{}" rx="{}" ry="{}"/></clipPath></defs>"#,
+            id,
+            fmt(2.0 * HALF_LENGTH),
+            fmt(2.0 * HALF_WIDTH),
+            fmt(CORNER_RADIUS),
+            fmt(CORNER_RADIUS),
+        );
+        boards(&mut svg, "#ffffff", "none");
+        let _ = writeln!(svg, "<g clip-path=\"url(#{}-boards)\">", id);
+        svg.push_str(&content);
+        svg.push_str("</g>\n");
+        boards(&mut svg, "none", "#000000");
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn render_rink(&self, svg: &mut String) {
+        line(svg, 0.0, "#c8102e", 1.0);
+        for side in [-1.0, 1.0] {
+            line(svg, side * BLUE_LINE_X, "#0033a0", 1.0);
+            line(svg, side * GOAL_LINE_X, "#c8102e", 0.17);
+
+            let (crease_x, crease_y) = to_svg(side * GOAL_LINE_X, CREASE_RADIUS);
+            let (_, crease_bottom) = to_svg(side * GOAL_LINE_X, -CREASE_RADIUS);
+            let sweep = if side > 0.0 { 0 } else { 1 };
+            let _ = writeln!(
+                svg,
+                r##"<path d="M {} {} A {} {} 0 0 {} {} {} Z" fill="#9fd3f5" stroke="#c8102e" stroke-width="0.17"/>"##,
+                fmt(crease_x),
+                fmt(crease_y),
+                fmt(CREASE_RADIUS),
+                fmt(CREASE_RADIUS),
+                sweep,
+                fmt(crease_x),
+                fmt(crease_bottom),
+            );
+
+            let (net_x, net_y) = to_svg(side * GOAL_LINE_X + side.min(0.0) * 3.33, 3.0);
+            let _ = writeln!(
+                svg,
+                r##"<rect x="{}" y="{}" width="3.33" height="6" fill="none" stroke="#c8102e" stroke-width="0.3"/>"##,
+                fmt(net_x),
+                fmt(net_y),
+            );
+
+            for dot_y in [-FACEOFF_DOT_Y, FACEOFF_DOT_Y] {
+                circle(svg, side * FACEOFF_DOT_X, dot_y, CIRCLE_RADIUS, "none");
+                circle(svg, side * FACEOFF_DOT_X, dot_y, 1.0, "#c8102e");
+                circle(svg, side * NEUTRAL_DOT_X, dot_y, 1.0, "#c8102e");
+            }
+        }
+        circle(svg, 0.0, 0.0, CIRCLE_RADIUS, "none");
+        circle(svg, 0.0, 0.0, 0.5, "#0033a0");
+    }
+
+    fn render_markers(&self, svg: &mut String) {
+        let mut palette = TEAM_PALETTE.iter().cycle();
+        let mut team_colors = self.team_colors.clone();
+        for marker in &self.markers {
+            team_colors
+                .entry(marker.team_id)
+                .or_insert_with(|| palette.next().unwrap().to_string());
+        }
+
+        for marker in &self.markers {
+            let color = match (self.color_by, marker.xg) {
+                (MarkerColor::Xg, Some(xg)) => xg_color(xg),
+                _ => escape(&team_colors[&marker.team_id]),
+            };
+            let point = if self.mirrored_teams.contains(&marker.team_id) {
+                RinkPoint::new(-marker.point.x, -marker.point.y)
+            } else {
+                marker.point
+            };
+            let (x, y) = to_svg(point.x, point.y);
+            let (radius, stroke, opacity) = if marker.is_goal {
+                (1.6, "#000000", 1.0)
+            } else {
+                (1.0, "none", 0.75)
+            };
+            let _ = writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="{}" fill-opacity="{}" stroke="{}" stroke-width="0.3"/>"#,
+                fmt(x),
+                fmt(y),
+                fmt(radius),
+                color,
+                fmt(opacity),
+                stroke,
+            );
+        }
+    }
+}
+
+fn render_heat_map(svg: &mut String, heat_map: &HeatMap) {
+    let max = heat_map.max();
+    if max <= 0.0 {
+        return;
+    }
+    for row in 0..heat_map.rows {
+        for column in 0..heat_map.columns {
+            let value = heat_map.get(column, row).unwrap_or_default();
+            if value <= 0.0 {
+                continue;
+            }
+            let x = column as f64 * heat_map.bin_size - HALF_LENGTH;
+            let y = (row + 1) as f64 * heat_map.bin_size - HALF_WIDTH;
+            let (svg_x, svg_y) = to_svg(x, y);
+            let _ = writeln!(
+                svg,
+                r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#de2d26" fill-opacity="{}"/>"##,
+                fmt(svg_x),
+                fmt(svg_y),
+                fmt(heat_map.bin_size),
+                fmt(heat_map.bin_size),
+                fmt(0.6 * value / max),
+            );
+        }
+    }
+}
+
+fn boards(svg: &mut String, fill: &str, stroke: &str) {
+    let _ = writeln!(
+        svg,
+        r#"<rect x="0" y="0" width="{}" height="{}" rx="{}" ry="{}" fill="{}" stroke="{}" stroke-width="0.5"/>"#,
+        fmt(2.0 * HALF_LENGTH),
+        fmt(2.0 * HALF_WIDTH),
+        fmt(CORNER_RADIUS),
+        fmt(CORNER_RADIUS),
+        fill,
+        stroke,
+    );
+}
+
+/// Converts rink coordinates (centre ice origin, `y` up) to SVG coordinates (top left origin, `y` down).
+fn to_svg(x: f64, y: f64) -> (f64, f64) {
+    (x + HALF_LENGTH, HALF_WIDTH - y)
+}
+
+fn line(svg: &mut String, x: f64, color: &str, width: f64) {
+    let (svg_x, _) = to_svg(x, 0.0);
+    let _ = writeln!(
+        svg,
+        r#"<line x1="{}" y1="0" x2="{}" y2="{}" stroke="{}" stroke-width="{}"/>"#,
+        fmt(svg_x),
+        fmt(svg_x),
+        fmt(2.0 * HALF_WIDTH),
+        color,
+        fmt(width),
+    );
+}
+
+fn circle(svg: &mut String, x: f64, y: f64, radius: f64, fill: &str) {
+    let (svg_x, svg_y) = to_svg(x, y);
+    let _ = writeln!(
+        svg,
+        r##"<circle cx="{}" cy="{}" r="{}" fill="{}" stroke="#c8102e" stroke-width="0.17"/>"##,
+        fmt(svg_x),
+        fmt(svg_y),
+        fmt(radius),
+        fill,
+    );
+}
+
+fn xg_color(xg: f64) -> String {
+    let t = (xg / HIGH_XG).clamp(0.0, 1.0);
+    let mix = |low: u8, high: u8| (low as f64 + (high as f64 - low as f64) * t).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        mix(LOW_XG_COLOR.0, HIGH_XG_COLOR.0),
+        mix(LOW_XG_COLOR.1, HIGH_XG_COLOR.1),
+        mix(LOW_XG_COLOR.2, HIGH_XG_COLOR.2),
+    )
+}
+
+/// Formats a number with at most two decimals, so output is stable and compact.
+fn fmt(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded == rounded.trunc() {
+        format!("{}", rounded as i64)
+    } else {
+        format!("{}", rounded)
+    }
+}
+
+/// Escapes a string for an XML attribute value.
+fn escape(value: &str) -> String {
+    let mut escaped = String::new();
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,499 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{Game, GameType},
+    ids::{SeasonId, TeamId},
+    rules::{GameResult, RuleSet},
+    Client,
+};
+
+/// A criteria used to order teams that are tied on points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Tiebreaker {
+    /// Fewer games played ranks higher.
+    GamesPlayed,
+    /// More wins ranks higher.
+    Wins,
+    /// More regulation wins (RW) ranks higher.
+    RegulationWins,
+    /// More regulation and overtime wins (ROW) ranks higher.
+    RegulationAndOvertimeWins,
+    /// More points earned in games between the tied teams ranks higher.
+    HeadToHeadPoints,
+    /// A higher goal differential ranks higher.
+    GoalDifferential,
+    /// More goals for ranks higher.
+    GoalsFor,
+}
+
+impl Tiebreaker {
+    /// A short description of the tiebreaker, used in [`TiebreakerExplanation`]s.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Tiebreaker::GamesPlayed => "fewer games played",
+            Tiebreaker::Wins => "wins",
+            Tiebreaker::RegulationWins => "regulation wins",
+            Tiebreaker::RegulationAndOvertimeWins => "regulation and overtime wins",
+            Tiebreaker::HeadToHeadPoints => "points in games between the tied teams",
+            Tiebreaker::GoalDifferential => "goal differential",
+            Tiebreaker::GoalsFor => "goals for",
+        }
+    }
+}
+
+/// The points awarded for each game result and the tiebreakers applied to teams tied on points.
+///
+/// See [`RuleSet::for_season`] for the ruleset used in a given season.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PointsRuleset {
+    pub win: i64,
+    pub tie: i64,
+    pub overtime_loss: i64,
+    pub shootout_loss: i64,
+    /// Applied in order once teams are tied on points.
+    pub tiebreakers: Vec<Tiebreaker>,
+}
+
+impl Default for PointsRuleset {
+    fn default() -> Self {
+        Self::shootout_era()
+    }
+}
+
+impl PointsRuleset {
+    /// Two points for a win, one for a tie and nothing for an overtime loss.
+    pub fn ties_era() -> Self {
+        Self {
+            win: 2,
+            tie: 1,
+            overtime_loss: 0,
+            shootout_loss: 0,
+            tiebreakers: vec![
+                Tiebreaker::Wins,
+                Tiebreaker::HeadToHeadPoints,
+                Tiebreaker::GoalDifferential,
+            ],
+        }
+    }
+
+    /// Ties remain, but an overtime loss is worth a point.
+    pub fn overtime_loss_era() -> Self {
+        Self {
+            overtime_loss: 1,
+            ..Self::ties_era()
+        }
+    }
+
+    /// No ties, and both overtime and shootout losses are worth a point.
+    pub fn shootout_era() -> Self {
+        Self {
+            win: 2,
+            tie: 0,
+            overtime_loss: 1,
+            shootout_loss: 1,
+            tiebreakers: vec![
+                Tiebreaker::GamesPlayed,
+                Tiebreaker::RegulationWins,
+                Tiebreaker::RegulationAndOvertimeWins,
+                Tiebreaker::Wins,
+                Tiebreaker::HeadToHeadPoints,
+                Tiebreaker::GoalDifferential,
+                Tiebreaker::GoalsFor,
+            ],
+        }
+    }
+}
+
+/// A team's record over the games counted by [`Standings`].
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamRecord {
+    pub team_id: TeamId,
+    pub games_played: i64,
+    pub wins: i64,
+    pub losses: i64,
+    pub ties: i64,
+    pub overtime_losses: i64,
+    pub shootout_losses: i64,
+    pub regulation_wins: i64,
+    pub regulation_and_overtime_wins: i64,
+    pub goals_for: i64,
+    pub goals_against: i64,
+    pub points: i64,
+}
+
+impl TeamRecord {
+    pub fn goal_differential(&self) -> i64 {
+        self.goals_for - self.goals_against
+    }
+
+    /// Points earned out of the points available.
+    pub fn points_percentage(&self, ruleset: &PointsRuleset) -> f64 {
+        if self.games_played == 0 {
+            return 0.0;
+        }
+        self.points as f64 / (self.games_played * ruleset.win) as f64
+    }
+}
+
+/// Why a team was ranked above the team directly below it, despite being tied on points.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TiebreakerExplanation {
+    pub team_below_id: TeamId,
+    /// `None` when every tiebreaker was equal and the teams were ordered by ID.
+    pub decided_by: Option<Tiebreaker>,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsRow {
+    pub rank: usize,
+    pub record: TeamRecord,
+    pub tiebreaker: Option<TiebreakerExplanation>,
+}
+
+/// An ordered table of teams, e.g. the whole league or a single division.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsTable {
+    pub name: String,
+    pub rows: Vec<StandingsRow>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TeamResult {
+    team_id: TeamId,
+    opponent_id: TeamId,
+    points: i64,
+}
+
+/// Standings computed locally from a list of games.
+///
+/// Only completed regular season games are counted. Callers are expected to pass the games of a
+/// single season, e.g. from [`Client::get_games_for_season_by_id`].
+///
+/// Results are classified, and tied teams ordered, with a [`RuleSet`].
+///
+/// # Example
+/// ```
+/// use nhl_rs::{Game, GameState, GameType, RuleSet, SeasonId, Standings, TeamId, Tiebreaker};
+///
+/// let team = |id| TeamId::new(id).unwrap();
+/// let final_game = |home_team_id, home_score, visiting_team_id, visiting_score, period| Game {
+///     game_type: GameType::RegularSeason,
+///     game_state_id: GameState::Official,
+///     game_date: "2024-01-01".to_string(),
+///     home_team_id,
+///     home_score,
+///     visiting_team_id,
+///     visiting_score,
+///     period: Some(period),
+///     ..Default::default()
+/// };
+/// let games = vec![
+///     final_game(team(1), 3, team(2), 1, 3),
+///     final_game(team(2), 2, team(1), 1, 3),
+///     final_game(team(1), 2, team(3), 1, 4),
+///     final_game(team(2), 4, team(3), 3, 5),
+/// ];
+///
+/// let rules = RuleSet::for_season(SeasonId::new(20232024).unwrap());
+/// let standings = Standings::compute(&games, rules, None);
+/// let league = standings.league();
+///
+/// assert_eq!(league.rows[0].record.team_id, team(1));
+/// assert_eq!(league.rows[0].record.points, 4);
+/// let explanation = league.rows[0].tiebreaker.as_ref().unwrap();
+/// assert_eq!(explanation.team_below_id, team(2));
+/// assert_eq!(explanation.decided_by, Some(Tiebreaker::RegulationAndOvertimeWins));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standings {
+    pub rules: RuleSet,
+    pub records: HashMap<TeamId, TeamRecord>,
+    results: Vec<TeamResult>,
+}
+
+impl Standings {
+    /// Computes the standings from `games`, counting only games played on or before `as_of`
+    /// (a `YYYY-MM-DD` date) when it is provided.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, RuleSet, SeasonId, Standings};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let season = SeasonId::new(19931994).unwrap();
+    /// let games = client.get_games_for_season_by_id(season).await?;
+    /// let standings = Standings::compute(&games, RuleSet::for_season(season), Some("1994-02-01"));
+    ///
+    /// for row in standings.league().rows {
+    ///     println!("{}. {} - {} points", row.rank, row.record.team_id, row.record.points);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub fn compute(games: &[Game], rules: RuleSet, as_of: Option<&str>) -> Self {
+        let mut records: HashMap<TeamId, TeamRecord> = HashMap::new();
+        let mut results = Vec::new();
+
+        let counted = games.iter().filter(|game| {
+            game.game_type == GameType::RegularSeason
+                && game.game_state_id.is_completed()
+                && as_of.is_none_or(|date| game_day(game) <= date)
+        });
+
+        for game in counted {
+            let sides = [
+                (
+                    game.home_team_id,
+                    game.visiting_team_id,
+                    game.home_score,
+                    game.visiting_score,
+                ),
+                (
+                    game.visiting_team_id,
+                    game.home_team_id,
+                    game.visiting_score,
+                    game.home_score,
+                ),
+            ];
+
+            for (team_id, opponent_id, goals_for, goals_against) in sides {
+                let Some(result) = rules.classify(game, team_id) else {
+                    continue;
+                };
+                let record = records.entry(team_id).or_insert_with(|| TeamRecord {
+                    team_id,
+                    ..Default::default()
+                });
+                let points = record_result(record, result, &rules);
+                record.goals_for += goals_for;
+                record.goals_against += goals_against;
+
+                results.push(TeamResult {
+                    team_id,
+                    opponent_id,
+                    points,
+                });
+            }
+        }
+
+        Self {
+            rules,
+            records,
+            results,
+        }
+    }
+
+    /// The whole league, ordered by points and then by the ruleset's tiebreakers.
+    pub fn league(&self) -> StandingsTable {
+        self.table("League", self.records.keys().copied().collect())
+    }
+
+    /// One table per group (e.g. conference or division), given a map of team ID to group name.
+    ///
+    /// Teams missing from `groups` are left out. Tables are sorted by name.
+    pub fn grouped(&self, groups: &HashMap<TeamId, String>) -> Vec<StandingsTable> {
+        let mut members: HashMap<&str, Vec<TeamId>> = HashMap::new();
+        for team_id in self.records.keys() {
+            if let Some(group) = groups.get(team_id) {
+                members.entry(group.as_str()).or_default().push(*team_id);
+            }
+        }
+
+        let mut tables = members
+            .into_iter()
+            .map(|(name, team_ids)| self.table(name, team_ids))
+            .collect::<Vec<_>>();
+        tables.sort_by(|a, b| a.name.cmp(&b.name));
+        tables
+    }
+
+    fn table(&self, name: &str, team_ids: Vec<TeamId>) -> StandingsTable {
+        let mut ranked = Vec::new();
+        let mut by_points: HashMap<i64, Vec<TeamId>> = HashMap::new();
+        for team_id in team_ids {
+            by_points
+                .entry(self.records[&team_id].points)
+                .or_default()
+                .push(team_id);
+        }
+        let mut groups = by_points.into_iter().collect::<Vec<_>>();
+        groups.sort_by_key(|(points, _)| Reverse(*points));
+
+        for (_, group) in groups {
+            self.break_ties(group, 0, &mut ranked);
+        }
+
+        let rows = ranked
+            .iter()
+            .enumerate()
+            .map(|(index, (team_id, _))| StandingsRow {
+                rank: index + 1,
+                record: self.records[team_id].clone(),
+                tiebreaker: ranked.get(index + 1).and_then(|(below_id, decided_by)| {
+                    let below = &self.records[below_id];
+                    let record = &self.records[team_id];
+                    (record.points == below.points).then(|| TiebreakerExplanation {
+                        team_below_id: *below_id,
+                        decided_by: *decided_by,
+                        description: match decided_by {
+                            Some(tiebreaker) => {
+                                format!(
+                                    "Ranked above team {} on {}",
+                                    below_id,
+                                    tiebreaker.description()
+                                )
+                            }
+                            None => format!(
+                                "Tied with team {} on every tiebreaker, ordered by team ID",
+                                below_id
+                            ),
+                        },
+                    })
+                }),
+            })
+            .collect();
+
+        StandingsTable {
+            name: name.to_string(),
+            rows,
+        }
+    }
+
+    /// Orders a group of teams tied on points by applying the tiebreakers from `index` onwards.
+    ///
+    /// Each team is pushed with the tiebreaker that separated it from the team ranked above it.
+    fn break_ties(
+        &self,
+        mut group: Vec<TeamId>,
+        index: usize,
+        ranked: &mut Vec<(TeamId, Option<Tiebreaker>)>,
+    ) {
+        let Some(tiebreaker) = self.rules.points.tiebreakers.get(index).copied() else {
+            group.sort();
+            ranked.extend(group.into_iter().map(|team_id| (team_id, None)));
+            return;
+        };
+        if group.len() == 1 {
+            ranked.push((group[0], None));
+            return;
+        }
+
+        let mut by_key: HashMap<i64, Vec<TeamId>> = HashMap::new();
+        for team_id in &group {
+            by_key
+                .entry(self.tiebreaker_key(tiebreaker, *team_id, &group))
+                .or_default()
+                .push(*team_id);
+        }
+        let mut sub_groups = by_key.into_iter().collect::<Vec<_>>();
+        sub_groups.sort_by_key(|(key, _)| Reverse(*key));
+
+        for (position, (_, sub_group)) in sub_groups.into_iter().enumerate() {
+            let start = ranked.len();
+            self.break_ties(sub_group, index + 1, ranked);
+            if position > 0 {
+                ranked[start].1 = Some(tiebreaker);
+            }
+        }
+    }
+
+    /// A key for `tiebreaker` where a larger value ranks higher.
+    fn tiebreaker_key(&self, tiebreaker: Tiebreaker, team_id: TeamId, tied: &[TeamId]) -> i64 {
+        let record = &self.records[&team_id];
+        match tiebreaker {
+            Tiebreaker::GamesPlayed => -record.games_played,
+            Tiebreaker::Wins => record.wins,
+            Tiebreaker::RegulationWins => record.regulation_wins,
+            Tiebreaker::RegulationAndOvertimeWins => record.regulation_and_overtime_wins,
+            Tiebreaker::HeadToHeadPoints => self
+                .results
+                .iter()
+                .filter(|result| result.team_id == team_id && tied.contains(&result.opponent_id))
+                .map(|result| result.points)
+                .sum(),
+            Tiebreaker::GoalDifferential => record.goal_differential(),
+            Tiebreaker::GoalsFor => record.goals_for,
+        }
+    }
+}
+
+impl Client {
+    /// Compute the standings of a season as of a date (`YYYY-MM-DD`) from the list of games,
+    /// using the [`RuleSet`] of that season.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, SeasonId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let season = SeasonId::new(19931994).unwrap();
+    /// let response = client
+    ///     .get_standings_for_season(season, Some("1994-02-01"))
+    ///     .await?;
+    ///
+    /// println!("League leader as of Feb 1, 1994: {:?}", response.league().rows[0]);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_standings_for_season(
+        &self,
+        season_id: SeasonId,
+        as_of: Option<&str>,
+    ) -> Result<Standings, u16> {
+        let games = self.get_games_for_season_by_id(season_id).await?;
+        Ok(Standings::compute(
+            &games,
+            RuleSet::for_season(season_id),
+            as_of,
+        ))
+    }
+}
+
+/// The `YYYY-MM-DD` part of the game date.
+fn game_day(game: &Game) -> &str {
+    game.game_date.get(..10).unwrap_or(&game.game_date)
+}
+
+/// Adds a result to a record, returning the points earned.
+fn record_result(record: &mut TeamRecord, result: GameResult, rules: &RuleSet) -> i64 {
+    record.games_played += 1;
+    match result {
+        GameResult::RegulationWin => {
+            record.wins += 1;
+            record.regulation_wins += 1;
+            record.regulation_and_overtime_wins += 1;
+        }
+        GameResult::OvertimeWin => {
+            record.wins += 1;
+            record.regulation_and_overtime_wins += 1;
+        }
+        GameResult::ShootoutWin => record.wins += 1,
+        GameResult::Loss => record.losses += 1,
+        GameResult::OvertimeLoss => record.overtime_losses += 1,
+        GameResult::ShootoutLoss => record.shootout_losses += 1,
+        GameResult::Tie => record.ties += 1,
+    }
+    let points = rules.points_for(result);
+    record.points += points;
+    points
+}
@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    http::get,
+    stats::{Report, ReportContext, ReportRow, StatsQuery, StatsResponse},
+    Client,
+};
+
+/// A goalie report of the stats REST API.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GoalieReport {
+    #[default]
+    Summary,
+    Advanced,
+    Bios,
+    DaysRest,
+    PenaltyShots,
+    SavesByStrength,
+    Shootout,
+    StartedVsRelieved,
+}
+
+impl GoalieReport {
+    /// The path of the report, e.g. `goalie/summary`.
+    pub fn path(&self) -> String {
+        let report = match self {
+            Self::Summary => "summary",
+            Self::Advanced => "advanced",
+            Self::Bios => "bios",
+            Self::DaysRest => "daysrest",
+            Self::PenaltyShots => "penaltyShots",
+            Self::SavesByStrength => "savesByStrength",
+            Self::Shootout => "shootout",
+            Self::StartedVsRelieved => "startedVsRelieved",
+        };
+        format!("goalie/{}", report)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalieSummary {
+    pub player_id: i64,
+    pub goalie_full_name: String,
+    pub last_name: String,
+    pub shoots_catches: Option<String>,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub games_started: Option<i64>,
+    pub wins: Option<i64>,
+    pub losses: Option<i64>,
+    pub ot_losses: Option<i64>,
+    pub ties: Option<i64>,
+    pub shots_against: Option<i64>,
+    pub saves: Option<i64>,
+    pub goals_against: Option<i64>,
+    pub save_pct: Option<f64>,
+    pub goals_against_average: Option<f64>,
+    pub shutouts: Option<i64>,
+    pub goals: Option<i64>,
+    pub assists: Option<i64>,
+    pub points: Option<i64>,
+    pub penalty_minutes: Option<i64>,
+    /// Time on ice, in seconds.
+    pub time_on_ice: Option<f64>,
+}
+
+impl Report for GoalieSummary {
+    fn path() -> String {
+        GoalieReport::Summary.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalieAdvanced {
+    pub player_id: i64,
+    pub goalie_full_name: String,
+    pub last_name: String,
+    pub shoots_catches: Option<String>,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub games_started: Option<i64>,
+    pub complete_games: Option<i64>,
+    pub complete_game_pct: Option<f64>,
+    pub incomplete_games: Option<i64>,
+    pub quality_start: Option<i64>,
+    pub quality_starts_pct: Option<f64>,
+    pub regulation_wins: Option<i64>,
+    pub regulation_losses: Option<i64>,
+    pub goals_against: Option<i64>,
+    pub goals_against_average: Option<f64>,
+    pub goals_for: Option<i64>,
+    pub goals_for_average: Option<f64>,
+    pub save_pct: Option<f64>,
+    pub shots_against_per_60: Option<f64>,
+}
+
+impl Report for GoalieAdvanced {
+    fn path() -> String {
+        GoalieReport::Advanced.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalieSavesByStrength {
+    pub player_id: i64,
+    pub goalie_full_name: String,
+    pub last_name: String,
+    pub shoots_catches: Option<String>,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub shots_against: Option<i64>,
+    pub saves: Option<i64>,
+    pub goals_against: Option<i64>,
+    pub save_pct: Option<f64>,
+    pub ev_shots_against: Option<i64>,
+    pub ev_saves: Option<i64>,
+    pub ev_goals_against: Option<i64>,
+    pub ev_save_pct: Option<f64>,
+    pub pp_shots_against: Option<i64>,
+    pub pp_saves: Option<i64>,
+    pub pp_goals_against: Option<i64>,
+    pub pp_save_pct: Option<f64>,
+    pub sh_shots_against: Option<i64>,
+    pub sh_saves: Option<i64>,
+    pub sh_goals_against: Option<i64>,
+    pub sh_save_pct: Option<f64>,
+}
+
+impl Report for GoalieSavesByStrength {
+    fn path() -> String {
+        GoalieReport::SavesByStrength.path()
+    }
+}
+
+impl Client {
+    /// Get the untyped rows of any goalie report.
+    ///
+    /// Use [`Client::get_stats`] for typed rows of the reports that have them.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned. If the
+    /// query has invalid dates, then `400` is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{stats::GoalieReport, ClientBuilder, GameType, SeasonId, StatsQuery};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let query = StatsQuery::new()
+    ///     .season(SeasonId::new(20232024).unwrap())
+    ///     .game_type(GameType::Playoffs);
+    /// let response = client.get_goalie_report(GoalieReport::DaysRest, &query).await?;
+    ///
+    /// println!("Got {} rows", response.total);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_goalie_report(
+        &self,
+        report: GoalieReport,
+        query: &StatsQuery,
+    ) -> Result<StatsResponse<ReportRow>, u16> {
+        let report_url = format!(
+            "{}/{}/{}",
+            self.stats_base_url,
+            self.language,
+            report.path()
+        );
+        get::<StatsResponse<ReportRow>>(&self.transport, query.url(&report_url)?).await
+    }
+}
@@ -0,0 +1,97 @@
+pub mod goalie;
+pub mod query;
+pub mod skater;
+pub mod team;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    http::get,
+    ids::{GameId, SeasonId},
+    Client,
+};
+
+pub use crate::stats::{
+    goalie::{GoalieAdvanced, GoalieReport, GoalieSavesByStrength, GoalieSummary},
+    query::{SortDirection, StatsQuery},
+    skater::{
+        SkaterFaceoffs, SkaterPenaltyKill, SkaterPowerPlay, SkaterRealtime, SkaterReport,
+        SkaterSummary, SkaterTimeOnIce,
+    },
+    team::{
+        TeamFaceoffs, TeamGoalsByPeriod, TeamLeadingTrailing, TeamPenaltyKill, TeamPowerPlay,
+        TeamRealtime, TeamReport, TeamShotType, TeamSummary,
+    },
+};
+
+/// An untyped report row.
+pub type ReportRow = serde_json::Map<String, serde_json::Value>;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsResponse<T> {
+    pub data: Vec<T>,
+    /// The number of rows matching the query, before paging.
+    pub total: i64,
+}
+
+/// The fields of a row that depend on how a [`StatsQuery`] groups rows.
+///
+/// Per-season rows have a `season_id`, per-game rows have the game fields and aggregated rows
+/// have neither.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportContext {
+    pub season_id: Option<SeasonId>,
+    pub game_id: Option<GameId>,
+    pub game_date: Option<String>,
+    /// `H` for home games and `R` for road games.
+    pub home_road: Option<String>,
+    pub opponent_team_abbrev: Option<String>,
+    pub team_abbrev: Option<String>,
+    /// Comma separated abbreviations of every team the player played for.
+    pub team_abbrevs: Option<String>,
+}
+
+/// A typed report row.
+pub trait Report: DeserializeOwned {
+    /// The path of the report, e.g. `skater/summary`.
+    fn path() -> String;
+}
+
+impl Client {
+    /// Get the typed rows of a report.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned. If the
+    /// query has invalid dates, then `400` is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{
+    ///     stats::SkaterSummary, ClientBuilder, GameType, SeasonId, SortDirection, StatsQuery,
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let query = StatsQuery::new()
+    ///     .season(SeasonId::new(20232024).unwrap())
+    ///     .game_type(GameType::RegularSeason)
+    ///     .sort("points", SortDirection::Descending)
+    ///     .limit(10);
+    /// let response = client.get_stats::<SkaterSummary>(&query).await?;
+    ///
+    /// for row in response.data {
+    ///     println!("{}: {} points", row.skater_full_name, row.points);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_stats<R: Report>(&self, query: &StatsQuery) -> Result<StatsResponse<R>, u16> {
+        let report_url = format!("{}/{}/{}", self.stats_base_url, self.language, R::path());
+        get::<StatsResponse<R>>(&self.transport, query.url(&report_url)?).await
+    }
+}
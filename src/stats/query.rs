@@ -0,0 +1,206 @@
+use reqwest::{StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::{date::is_valid_date, game::GameType, ids::SeasonId};
+
+/// The direction of a [`StatsQuery`] sort.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SortDirection {
+    Ascending,
+    #[default]
+    Descending,
+}
+
+impl SortDirection {
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            Self::Ascending => "ASC",
+            Self::Descending => "DESC",
+        }
+    }
+}
+
+/// The query of a stats report: which seasons, dates and game types to include, how rows are
+/// grouped, sorted and paged.
+///
+/// By default rows are per player (or team) per season, over every season and game type.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{GameType, SeasonId, SortDirection, StatsQuery};
+///
+/// let query = StatsQuery::new()
+///     .seasons(SeasonId::new(20202021).unwrap(), SeasonId::new(20232024).unwrap())
+///     .game_type(GameType::RegularSeason)
+///     .aggregate(true)
+///     .sort("points", SortDirection::Descending)
+///     .limit(10);
+///
+/// assert_eq!(
+///     query.cayenne_exp(),
+///     "gameTypeId=2 and seasonId>=20202021 and seasonId<=20232024"
+/// );
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StatsQuery {
+    aggregate: bool,
+    per_game: bool,
+    from_season: Option<SeasonId>,
+    to_season: Option<SeasonId>,
+    from_date: Option<String>,
+    to_date: Option<String>,
+    game_type: Option<GameType>,
+    filters: Vec<String>,
+    sort: Vec<(String, SortDirection)>,
+    start: Option<i64>,
+    limit: Option<i64>,
+}
+
+impl StatsQuery {
+    /// Creates a new [`StatsQuery`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only includes a single season.
+    pub fn season(self, season: SeasonId) -> Self {
+        self.seasons(season, season)
+    }
+
+    /// Only includes the seasons from `from` to `to`, inclusive.
+    pub fn seasons(mut self, from: SeasonId, to: SeasonId) -> Self {
+        self.from_season = Some(from);
+        self.to_season = Some(to);
+        self
+    }
+
+    /// Only includes games played from `from` to `to`, inclusive, both as `YYYY-MM-DD`.
+    ///
+    /// Reports requested with invalid dates, or with `from` after `to`, fail with `400`.
+    ///
+    /// # Example
+    /// ```
+    /// use nhl_rs::StatsQuery;
+    ///
+    /// let query = StatsQuery::new().dates("2024-01-01", "2024-01-31");
+    ///
+    /// assert_eq!(
+    ///     query.cayenne_exp(),
+    ///     "gameDate>=\"2024-01-01\" and gameDate<=\"2024-01-31 23:59:59\""
+    /// );
+    /// ```
+    pub fn dates(mut self, from: &str, to: &str) -> Self {
+        self.from_date = Some(from.to_string());
+        self.to_date = Some(to.to_string());
+        self
+    }
+
+    /// Only includes games of a type, e.g. [`GameType::Playoffs`].
+    pub fn game_type(mut self, game_type: GameType) -> Self {
+        self.game_type = Some(game_type);
+        self
+    }
+
+    /// Whether rows are summed over every included season (one row per player or team) instead
+    /// of returning a row per season.
+    pub fn aggregate(mut self, aggregate: bool) -> Self {
+        self.aggregate = aggregate;
+        self
+    }
+
+    /// Whether rows are per game instead of per season.
+    pub fn per_game(mut self, per_game: bool) -> Self {
+        self.per_game = per_game;
+        self
+    }
+
+    /// Adds a raw Cayenne expression, e.g. `playerId=8478402` or `gamesPlayed>=20`.
+    ///
+    /// Expressions are combined with `and`.
+    pub fn filter(mut self, expression: &str) -> Self {
+        self.filters.push(expression.to_string());
+        self
+    }
+
+    /// Sorts rows by a property, e.g. `points`. Later sorts break ties of earlier ones.
+    pub fn sort(mut self, property: &str, direction: SortDirection) -> Self {
+        self.sort.push((property.to_string(), direction));
+        self
+    }
+
+    /// Skips the first `start` rows.
+    pub fn start(mut self, start: i64) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Returns at most `limit` rows. A limit of `-1` returns every row.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// The Cayenne expression used to filter rows.
+    pub fn cayenne_exp(&self) -> String {
+        let mut conditions = vec![];
+        if let Some(game_type) = self.game_type {
+            conditions.push(format!("gameTypeId={}", game_type.id()));
+        }
+        if let Some(from_season) = self.from_season {
+            conditions.push(format!("seasonId>={}", from_season.id()));
+        }
+        if let Some(to_season) = self.to_season {
+            conditions.push(format!("seasonId<={}", to_season.id()));
+        }
+        if let Some(from_date) = &self.from_date {
+            conditions.push(format!("gameDate>=\"{}\"", from_date));
+        }
+        if let Some(to_date) = &self.to_date {
+            conditions.push(format!("gameDate<=\"{} 23:59:59\"", to_date));
+        }
+        conditions.extend(self.filters.iter().cloned());
+        conditions.join(" and ")
+    }
+
+    /// Builds the URL of a report at `report_url` for this query.
+    ///
+    /// # Errors
+    /// Returns `400` if the dates are invalid or the URL can't be built.
+    pub(crate) fn url(&self, report_url: &str) -> Result<String, u16> {
+        if let (Some(from_date), Some(to_date)) = (&self.from_date, &self.to_date) {
+            if !is_valid_date(from_date) || !is_valid_date(to_date) || from_date > to_date {
+                return Err(StatusCode::BAD_REQUEST.as_u16());
+            }
+        }
+
+        let mut params = vec![
+            ("isAggregate", self.aggregate.to_string()),
+            ("isGame", self.per_game.to_string()),
+        ];
+        if !self.sort.is_empty() {
+            let sort = self
+                .sort
+                .iter()
+                .map(|(property, direction)| {
+                    serde_json::json!({ "property": property, "direction": direction.code() })
+                })
+                .collect::<Vec<_>>();
+            params.push(("sort", serde_json::Value::from(sort).to_string()));
+        }
+        if let Some(start) = self.start {
+            params.push(("start", start.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        let cayenne_exp = self.cayenne_exp();
+        if !cayenne_exp.is_empty() {
+            params.push(("cayenneExp", cayenne_exp));
+        }
+
+        match Url::parse_with_params(report_url, &params) {
+            Ok(url) => Ok(url.to_string()),
+            Err(_) => Err(StatusCode::BAD_REQUEST.as_u16()),
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    http::get,
+    stats::{Report, ReportContext, ReportRow, StatsQuery, StatsResponse},
+    Client,
+};
+
+/// A skater report of the stats REST API.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SkaterReport {
+    #[default]
+    Summary,
+    Bios,
+    Faceoffs,
+    FaceoffWins,
+    GoalsForAgainst,
+    Penalties,
+    PenaltyKill,
+    PenaltyShots,
+    PowerPlay,
+    PuckPossessions,
+    Realtime,
+    ScoringPerGame,
+    ScoringRates,
+    Shootout,
+    ShotType,
+    TimeOnIce,
+}
+
+impl SkaterReport {
+    /// The path of the report, e.g. `skater/summary`.
+    pub fn path(&self) -> String {
+        let report = match self {
+            Self::Summary => "summary",
+            Self::Bios => "bios",
+            Self::Faceoffs => "faceoffpercentages",
+            Self::FaceoffWins => "faceoffwins",
+            Self::GoalsForAgainst => "goalsForAgainst",
+            Self::Penalties => "penalties",
+            Self::PenaltyKill => "penaltykill",
+            Self::PenaltyShots => "penaltyShots",
+            Self::PowerPlay => "powerplay",
+            Self::PuckPossessions => "puckPossessions",
+            Self::Realtime => "realtime",
+            Self::ScoringPerGame => "scoringpergame",
+            Self::ScoringRates => "scoringRates",
+            Self::Shootout => "shootout",
+            Self::ShotType => "shottype",
+            Self::TimeOnIce => "timeonice",
+        };
+        format!("skater/{}", report)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkaterSummary {
+    pub player_id: i64,
+    pub skater_full_name: String,
+    pub last_name: String,
+    pub position_code: String,
+    pub shoots_catches: Option<String>,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub goals: i64,
+    pub assists: i64,
+    pub points: i64,
+    pub plus_minus: Option<i64>,
+    pub penalty_minutes: Option<i64>,
+    pub points_per_game: Option<f64>,
+    pub ev_goals: Option<i64>,
+    pub ev_points: Option<i64>,
+    pub pp_goals: Option<i64>,
+    pub pp_points: Option<i64>,
+    pub sh_goals: Option<i64>,
+    pub sh_points: Option<i64>,
+    pub ot_goals: Option<i64>,
+    pub game_winning_goals: Option<i64>,
+    pub shots: Option<i64>,
+    pub shooting_pct: Option<f64>,
+    pub faceoff_win_pct: Option<f64>,
+    /// Average time on ice per game, in seconds.
+    pub time_on_ice_per_game: Option<f64>,
+}
+
+impl Report for SkaterSummary {
+    fn path() -> String {
+        SkaterReport::Summary.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkaterRealtime {
+    pub player_id: i64,
+    pub skater_full_name: String,
+    pub last_name: String,
+    pub position_code: String,
+    pub shoots_catches: Option<String>,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub hits: Option<i64>,
+    pub hits_per_60: Option<f64>,
+    pub blocked_shots: Option<i64>,
+    pub blocked_shots_per_60: Option<f64>,
+    pub giveaways: Option<i64>,
+    pub giveaways_per_60: Option<f64>,
+    pub takeaways: Option<i64>,
+    pub takeaways_per_60: Option<f64>,
+    pub missed_shots: Option<i64>,
+    pub first_goals: Option<i64>,
+    pub empty_net_goals: Option<i64>,
+    pub empty_net_assists: Option<i64>,
+    pub empty_net_points: Option<i64>,
+    /// Average time on ice per game, in seconds.
+    pub time_on_ice_per_game: Option<f64>,
+}
+
+impl Report for SkaterRealtime {
+    fn path() -> String {
+        SkaterReport::Realtime.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkaterFaceoffs {
+    pub player_id: i64,
+    pub skater_full_name: String,
+    pub last_name: String,
+    pub position_code: String,
+    pub shoots_catches: Option<String>,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub total_faceoffs: Option<i64>,
+    pub faceoff_win_pct: Option<f64>,
+    pub defensive_zone_faceoffs: Option<i64>,
+    pub defensive_zone_faceoff_pct: Option<f64>,
+    pub neutral_zone_faceoffs: Option<i64>,
+    pub neutral_zone_faceoff_pct: Option<f64>,
+    pub offensive_zone_faceoffs: Option<i64>,
+    pub offensive_zone_faceoff_pct: Option<f64>,
+    pub even_strength_faceoffs: Option<i64>,
+    pub even_strength_faceoff_pct: Option<f64>,
+    pub pp_faceoffs: Option<i64>,
+    pub pp_faceoff_pct: Option<f64>,
+    pub sh_faceoffs: Option<i64>,
+    pub sh_faceoff_pct: Option<f64>,
+    /// Average time on ice per game, in seconds.
+    pub time_on_ice_per_game: Option<f64>,
+}
+
+impl Report for SkaterFaceoffs {
+    fn path() -> String {
+        SkaterReport::Faceoffs.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkaterPenaltyKill {
+    pub player_id: i64,
+    pub skater_full_name: String,
+    pub last_name: String,
+    pub position_code: String,
+    pub shoots_catches: Option<String>,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub sh_goals: Option<i64>,
+    pub sh_assists: Option<i64>,
+    pub sh_primary_assists: Option<i64>,
+    pub sh_secondary_assists: Option<i64>,
+    pub sh_points: Option<i64>,
+    pub sh_shots: Option<i64>,
+    pub sh_shooting_pct: Option<f64>,
+    pub sh_goals_per_60: Option<f64>,
+    pub sh_points_per_60: Option<f64>,
+    /// Short-handed time on ice, in seconds.
+    pub sh_time_on_ice: Option<f64>,
+    /// Average short-handed time on ice per game, in seconds.
+    pub sh_time_on_ice_per_game: Option<f64>,
+    pub sh_time_on_ice_pct_per_game: Option<f64>,
+}
+
+impl Report for SkaterPenaltyKill {
+    fn path() -> String {
+        SkaterReport::PenaltyKill.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkaterPowerPlay {
+    pub player_id: i64,
+    pub skater_full_name: String,
+    pub last_name: String,
+    pub position_code: String,
+    pub shoots_catches: Option<String>,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub pp_goals: Option<i64>,
+    pub pp_assists: Option<i64>,
+    pub pp_primary_assists: Option<i64>,
+    pub pp_secondary_assists: Option<i64>,
+    pub pp_points: Option<i64>,
+    pub pp_shots: Option<i64>,
+    pub pp_shooting_pct: Option<f64>,
+    pub pp_goals_per_60: Option<f64>,
+    pub pp_points_per_60: Option<f64>,
+    /// Power play time on ice, in seconds.
+    pub pp_time_on_ice: Option<f64>,
+    /// Average power play time on ice per game, in seconds.
+    pub pp_time_on_ice_per_game: Option<f64>,
+    pub pp_time_on_ice_pct_per_game: Option<f64>,
+}
+
+impl Report for SkaterPowerPlay {
+    fn path() -> String {
+        SkaterReport::PowerPlay.path()
+    }
+}
+
+/// Times on ice, in seconds.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkaterTimeOnIce {
+    pub player_id: i64,
+    pub skater_full_name: String,
+    pub last_name: String,
+    pub position_code: String,
+    pub shoots_catches: Option<String>,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub time_on_ice: Option<f64>,
+    pub time_on_ice_per_game: Option<f64>,
+    pub time_on_ice_per_shift: Option<f64>,
+    pub ev_time_on_ice: Option<f64>,
+    pub ev_time_on_ice_per_game: Option<f64>,
+    pub pp_time_on_ice: Option<f64>,
+    pub pp_time_on_ice_per_game: Option<f64>,
+    pub sh_time_on_ice: Option<f64>,
+    pub sh_time_on_ice_per_game: Option<f64>,
+    pub ot_time_on_ice: Option<f64>,
+    pub ot_time_on_ice_per_ot_game: Option<f64>,
+    pub shifts: Option<i64>,
+    pub shifts_per_game: Option<f64>,
+}
+
+impl Report for SkaterTimeOnIce {
+    fn path() -> String {
+        SkaterReport::TimeOnIce.path()
+    }
+}
+
+impl Client {
+    /// Get the untyped rows of any skater report.
+    ///
+    /// Use [`Client::get_stats`] for typed rows of the reports that have them.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned. If the
+    /// query has invalid dates, then `400` is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{stats::SkaterReport, ClientBuilder, SeasonId, StatsQuery};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let query = StatsQuery::new()
+    ///     .season(SeasonId::new(20232024).unwrap())
+    ///     .filter("playerId=8478402")
+    ///     .per_game(true);
+    /// let response = client.get_skater_report(SkaterReport::Bios, &query).await?;
+    ///
+    /// println!("Got {} rows", response.total);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_skater_report(
+        &self,
+        report: SkaterReport,
+        query: &StatsQuery,
+    ) -> Result<StatsResponse<ReportRow>, u16> {
+        let report_url = format!(
+            "{}/{}/{}",
+            self.stats_base_url,
+            self.language,
+            report.path()
+        );
+        get::<StatsResponse<ReportRow>>(&self.transport, query.url(&report_url)?).await
+    }
+}
@@ -0,0 +1,350 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    http::get,
+    ids::TeamId,
+    stats::{Report, ReportContext, ReportRow, StatsQuery, StatsResponse},
+    Client,
+};
+
+/// A team report of the stats REST API.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TeamReport {
+    #[default]
+    Summary,
+    DaysBetweenGames,
+    Faceoffs,
+    FaceoffWins,
+    GoalsAgainstByStrength,
+    GoalsByPeriod,
+    GoalsForByStrength,
+    LeadingTrailing,
+    OutshootOutshotBy,
+    Penalties,
+    PenaltyKill,
+    Percentages,
+    PowerPlay,
+    Realtime,
+    ShotType,
+}
+
+impl TeamReport {
+    /// The path of the report, e.g. `team/summary`.
+    pub fn path(&self) -> String {
+        let report = match self {
+            Self::Summary => "summary",
+            Self::DaysBetweenGames => "daysbetweengames",
+            Self::Faceoffs => "faceoffpercentages",
+            Self::FaceoffWins => "faceoffwins",
+            Self::GoalsAgainstByStrength => "goalsagainstbystrength",
+            Self::GoalsByPeriod => "goalsbyperiod",
+            Self::GoalsForByStrength => "goalsforbystrength",
+            Self::LeadingTrailing => "leadingtrailing",
+            Self::OutshootOutshotBy => "outshootoutshotby",
+            Self::Penalties => "penalties",
+            Self::PenaltyKill => "penaltykill",
+            Self::Percentages => "percentages",
+            Self::PowerPlay => "powerplay",
+            Self::Realtime => "realtime",
+            Self::ShotType => "shottype",
+        };
+        format!("team/{}", report)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSummary {
+    pub team_id: TeamId,
+    pub team_full_name: String,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub wins: Option<i64>,
+    pub losses: Option<i64>,
+    pub ot_losses: Option<i64>,
+    pub ties: Option<i64>,
+    pub points: Option<i64>,
+    pub point_pct: Option<f64>,
+    pub regulation_and_ot_wins: Option<i64>,
+    pub wins_in_regulation: Option<i64>,
+    pub wins_in_shootout: Option<i64>,
+    pub goals_for: Option<i64>,
+    pub goals_against: Option<i64>,
+    pub goals_for_per_game: Option<f64>,
+    pub goals_against_per_game: Option<f64>,
+    pub shots_for_per_game: Option<f64>,
+    pub shots_against_per_game: Option<f64>,
+    pub power_play_pct: Option<f64>,
+    pub power_play_net_pct: Option<f64>,
+    pub penalty_kill_pct: Option<f64>,
+    pub penalty_kill_net_pct: Option<f64>,
+    pub faceoff_win_pct: Option<f64>,
+}
+
+impl Report for TeamSummary {
+    fn path() -> String {
+        TeamReport::Summary.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamFaceoffs {
+    pub team_id: TeamId,
+    pub team_full_name: String,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub total_faceoffs: Option<i64>,
+    pub faceoffs_won: Option<i64>,
+    pub faceoffs_lost: Option<i64>,
+    pub faceoff_win_pct: Option<f64>,
+    pub defensive_zone_faceoffs: Option<i64>,
+    pub defensive_zone_faceoff_pct: Option<f64>,
+    pub neutral_zone_faceoffs: Option<i64>,
+    pub neutral_zone_faceoff_pct: Option<f64>,
+    pub offensive_zone_faceoffs: Option<i64>,
+    pub offensive_zone_faceoff_pct: Option<f64>,
+    pub ev_faceoffs: Option<i64>,
+    pub ev_faceoff_pct: Option<f64>,
+    pub pp_faceoffs: Option<i64>,
+    pub pp_faceoff_pct: Option<f64>,
+    pub sh_faceoffs: Option<i64>,
+    pub sh_faceoff_pct: Option<f64>,
+}
+
+impl Report for TeamFaceoffs {
+    fn path() -> String {
+        TeamReport::Faceoffs.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamPowerPlay {
+    pub team_id: TeamId,
+    pub team_full_name: String,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub pp_opportunities: Option<i64>,
+    pub pp_opportunities_per_game: Option<f64>,
+    pub pp_goals_for: Option<i64>,
+    pub pp_net_goals: Option<i64>,
+    pub sh_goals_against: Option<i64>,
+    pub power_play_pct: Option<f64>,
+    pub power_play_net_pct: Option<f64>,
+    /// Average power play time on ice per game, in seconds.
+    pub pp_time_on_ice_per_game: Option<f64>,
+}
+
+impl Report for TeamPowerPlay {
+    fn path() -> String {
+        TeamReport::PowerPlay.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamPenaltyKill {
+    pub team_id: TeamId,
+    pub team_full_name: String,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub times_shorthanded: Option<i64>,
+    pub times_shorthanded_per_game: Option<f64>,
+    pub pp_goals_against: Option<i64>,
+    pub sh_goals_for: Option<i64>,
+    pub pk_net_goals: Option<i64>,
+    pub penalty_kill_pct: Option<f64>,
+    pub penalty_kill_net_pct: Option<f64>,
+    /// Average short-handed time on ice per game, in seconds.
+    pub sh_time_on_ice_per_game: Option<f64>,
+}
+
+impl Report for TeamPenaltyKill {
+    fn path() -> String {
+        TeamReport::PenaltyKill.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamRealtime {
+    pub team_id: TeamId,
+    pub team_full_name: String,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub hits: Option<i64>,
+    pub hits_per_60: Option<f64>,
+    pub blocked_shots: Option<i64>,
+    pub blocked_shots_per_60: Option<f64>,
+    pub giveaways: Option<i64>,
+    pub giveaways_per_60: Option<f64>,
+    pub takeaways: Option<i64>,
+    pub takeaways_per_60: Option<f64>,
+    pub missed_shots: Option<i64>,
+    pub empty_net_goals: Option<i64>,
+    pub sat_pct: Option<f64>,
+    /// Average 5-on-5 time on ice per game, in seconds.
+    pub time_on_ice_per_game_5v5: Option<f64>,
+}
+
+impl Report for TeamRealtime {
+    fn path() -> String {
+        TeamReport::Realtime.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamGoalsByPeriod {
+    pub team_id: TeamId,
+    pub team_full_name: String,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub goals_for: Option<i64>,
+    pub goals_against: Option<i64>,
+    pub period_1_goals_for: Option<i64>,
+    pub period_1_goals_against: Option<i64>,
+    pub period_2_goals_for: Option<i64>,
+    pub period_2_goals_against: Option<i64>,
+    pub period_3_goals_for: Option<i64>,
+    pub period_3_goals_against: Option<i64>,
+    pub ot_goals_for: Option<i64>,
+    pub ot_goals_against: Option<i64>,
+}
+
+impl Report for TeamGoalsByPeriod {
+    fn path() -> String {
+        TeamReport::GoalsByPeriod.path()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamShotType {
+    pub team_id: TeamId,
+    pub team_full_name: String,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub goals_backhand: Option<i64>,
+    pub goals_bat: Option<i64>,
+    pub goals_between_legs: Option<i64>,
+    pub goals_cradle: Option<i64>,
+    pub goals_deflected: Option<i64>,
+    pub goals_poke: Option<i64>,
+    pub goals_slap: Option<i64>,
+    pub goals_snap: Option<i64>,
+    pub goals_tip_in: Option<i64>,
+    pub goals_wrap_around: Option<i64>,
+    pub goals_wrist: Option<i64>,
+    pub shots_on_net_backhand: Option<i64>,
+    pub shots_on_net_bat: Option<i64>,
+    pub shots_on_net_between_legs: Option<i64>,
+    pub shots_on_net_cradle: Option<i64>,
+    pub shots_on_net_deflected: Option<i64>,
+    pub shots_on_net_poke: Option<i64>,
+    pub shots_on_net_slap: Option<i64>,
+    pub shots_on_net_snap: Option<i64>,
+    pub shots_on_net_tip_in: Option<i64>,
+    pub shots_on_net_wrap_around: Option<i64>,
+    pub shots_on_net_wrist: Option<i64>,
+}
+
+impl Report for TeamShotType {
+    fn path() -> String {
+        TeamReport::ShotType.path()
+    }
+}
+
+/// Results after leading or trailing after the first and second periods.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamLeadingTrailing {
+    pub team_id: TeamId,
+    pub team_full_name: String,
+    #[serde(flatten)]
+    pub context: ReportContext,
+    pub games_played: i64,
+    pub wins_after_lead_1p: Option<i64>,
+    #[serde(rename = "lossAfterLead1p")]
+    pub losses_after_lead_1p: Option<i64>,
+    #[serde(rename = "otLossAfterLead1p")]
+    pub ot_losses_after_lead_1p: Option<i64>,
+    pub win_pct_after_lead_1p: Option<f64>,
+    pub wins_after_lead_2p: Option<i64>,
+    #[serde(rename = "lossAfterLead2p")]
+    pub losses_after_lead_2p: Option<i64>,
+    #[serde(rename = "otLossAfterLead2p")]
+    pub ot_losses_after_lead_2p: Option<i64>,
+    pub win_pct_after_lead_2p: Option<f64>,
+    pub wins_after_trail_1p: Option<i64>,
+    #[serde(rename = "lossAfterTrail1p")]
+    pub losses_after_trail_1p: Option<i64>,
+    #[serde(rename = "otLossAfterTrail1p")]
+    pub ot_losses_after_trail_1p: Option<i64>,
+    pub win_pct_after_trail_1p: Option<f64>,
+    pub wins_after_trail_2p: Option<i64>,
+    #[serde(rename = "lossAfterTrail2p")]
+    pub losses_after_trail_2p: Option<i64>,
+    #[serde(rename = "otLossAfterTrail2p")]
+    pub ot_losses_after_trail_2p: Option<i64>,
+    pub win_pct_after_trail_2p: Option<f64>,
+}
+
+impl Report for TeamLeadingTrailing {
+    fn path() -> String {
+        TeamReport::LeadingTrailing.path()
+    }
+}
+
+impl Client {
+    /// Get the untyped rows of any team report.
+    ///
+    /// Use [`Client::get_stats`] for typed rows of the reports that have them.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned. If the
+    /// query has invalid dates, then `400` is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{stats::TeamReport, ClientBuilder, StatsQuery};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let query = StatsQuery::new()
+    ///     .dates("2024-01-01", "2024-01-31")
+    ///     .aggregate(true);
+    /// let response = client
+    ///     .get_team_report(TeamReport::OutshootOutshotBy, &query)
+    ///     .await?;
+    ///
+    /// println!("Got {} rows", response.total);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_team_report(
+        &self,
+        report: TeamReport,
+        query: &StatsQuery,
+    ) -> Result<StatsResponse<ReportRow>, u16> {
+        let report_url = format!(
+            "{}/{}/{}",
+            self.stats_base_url,
+            self.language,
+            report.path()
+        );
+        get::<StatsResponse<ReportRow>>(&self.transport, query.url(&report_url)?).await
+    }
+}
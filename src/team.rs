@@ -0,0 +1,227 @@
+use crate::{franchise::Franchise, game::Game, http::get, ids::TeamId, Client};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamResponse {
+    pub data: Vec<Team>,
+    pub total: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    pub id: TeamId,
+    pub franchise_id: Option<i64>,
+    pub full_name: String,
+    pub league_id: Option<i64>,
+    pub raw_tricode: String,
+    pub tri_code: String,
+}
+
+/// A side of a [`Game`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GameSide {
+    Home,
+    Visiting,
+}
+
+impl Game {
+    /// The id of the team playing on a `side`.
+    pub fn team_id(&self, side: GameSide) -> TeamId {
+        match side {
+            GameSide::Home => self.home_team_id,
+            GameSide::Visiting => self.visiting_team_id,
+        }
+    }
+}
+
+impl Franchise {
+    /// The teams (e.g. the Quebec Nordiques and Colorado Avalanche) that have played as this
+    /// franchise.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let teams = client.get_teams().await?;
+    /// let franchise = client.get_franchise_by_id(27).await?.unwrap();
+    ///
+    /// for team in franchise.teams(&teams) {
+    ///     println!("{} ({})", team.full_name, team.tri_code);
+    /// }
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub fn teams(&self, teams: &[Team]) -> Vec<Team> {
+        teams
+            .iter()
+            .filter(|team| team.franchise_id == Some(self.id))
+            .cloned()
+            .collect::<Vec<_>>()
+    }
+}
+
+impl Client {
+    /// Get a list of teams, past and present.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_teams().await?;
+    ///
+    /// println!("Got {} teams", response.len());
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_teams(&self) -> Result<Vec<Team>, u16> {
+        let url = format!("{}/{}/team", self.stats_base_url, self.language);
+        let result = get::<TeamResponse>(&self.transport, url).await;
+        match result {
+            Ok(response) => Ok(response.data),
+            Err(status_code) => Err(status_code),
+        }
+    }
+
+    /// Get a team by an `id`.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, TeamId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_team_by_id(TeamId::new(10).unwrap()).await?;
+    ///
+    /// println!("Team with the id of 10: {:?}", response);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_team_by_id(&self, id: TeamId) -> Result<Option<Team>, u16> {
+        let result = self.get_teams().await?;
+        Ok(result
+            .into_iter()
+            .filter(|team| team.id == id)
+            .collect::<Vec<_>>()
+            .first()
+            .cloned())
+    }
+
+    /// Get a team by a `tri_code`, e.g. `TOR`.
+    ///
+    /// Tri-codes are reused over time, so the first team with a matching tri-code is returned.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_team_by_tri_code("tor").await?;
+    ///
+    /// println!("Team with the tri-code of 'TOR': {:?}", response);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_team_by_tri_code(&self, tri_code: &str) -> Result<Option<Team>, u16> {
+        let result = self.get_teams().await?;
+        let code = tri_code.to_uppercase();
+        Ok(result
+            .into_iter()
+            .filter(|team| team.tri_code.to_uppercase() == code)
+            .collect::<Vec<_>>()
+            .first()
+            .cloned())
+    }
+
+    /// Get the team that played on a `side` of a `game`.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameId, GameSide};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let game = client.get_game_by_id(GameId::new(2017020120).unwrap()).await?.unwrap();
+    /// let response = client.team_for_game_side(&game, GameSide::Home).await?;
+    ///
+    /// println!("Home team: {:?}", response);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn team_for_game_side(
+        &self,
+        game: &Game,
+        side: GameSide,
+    ) -> Result<Option<Team>, u16> {
+        self.get_team_by_id(game.team_id(side)).await
+    }
+
+    /// Get the franchise a team belongs to by the team `id`.
+    ///
+    /// Relocated teams resolve to the same franchise, e.g. both the Quebec Nordiques and the
+    /// Colorado Avalanche resolve to the Avalanche franchise.
+    ///
+    /// # Errors
+    /// If the NHL API throws an error, then the corresponding HTTP error code is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, TeamId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let response = client.get_franchise_by_team_id(TeamId::new(32).unwrap()).await?;
+    ///
+    /// println!("Franchise of the Quebec Nordiques: {:?}", response);
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub async fn get_franchise_by_team_id(&self, id: TeamId) -> Result<Option<Franchise>, u16> {
+        let Some(franchise_id) = self
+            .get_team_by_id(id)
+            .await?
+            .and_then(|team| team.franchise_id)
+        else {
+            return Ok(None);
+        };
+        self.get_franchise_by_id(franchise_id).await
+    }
+}
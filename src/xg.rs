@@ -0,0 +1,436 @@
+use std::{collections::HashMap, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{Play, PlayByPlayResponse},
+    ids::GameId,
+    rink::RinkPoint,
+};
+
+/// Seconds between two shot attempts by the same team for the second one to count as a rebound.
+const REBOUND_WINDOW_SECONDS: i64 = 3;
+/// Seconds between an event outside the offensive zone and a shot for the shot to count as a rush.
+const RUSH_WINDOW_SECONDS: i64 = 4;
+
+/// The type of shot as reported by the play-by-play feed.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ShotType {
+    #[default]
+    Wrist,
+    Snap,
+    Slap,
+    Backhand,
+    TipIn,
+    Deflected,
+    WrapAround,
+    Other,
+}
+
+impl ShotType {
+    /// Maps a play-by-play `shot_type` (e.g. `"tip-in"`) to a [`ShotType`].
+    pub fn from_api(shot_type: &str) -> Self {
+        match shot_type {
+            "wrist" => ShotType::Wrist,
+            "snap" => ShotType::Snap,
+            "slap" => ShotType::Slap,
+            "backhand" => ShotType::Backhand,
+            "tip-in" => ShotType::TipIn,
+            "deflected" => ShotType::Deflected,
+            "wrap-around" => ShotType::WrapAround,
+            _ => ShotType::Other,
+        }
+    }
+}
+
+/// The manpower situation from the shooting team's point of view.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Strength {
+    #[default]
+    Even,
+    PowerPlay,
+    ShortHanded,
+    /// The opposing goalie has been pulled.
+    EmptyNet,
+}
+
+impl Strength {
+    /// Derives the strength from a play-by-play `situation_code`.
+    ///
+    /// The code is four digits: away goalie in net, away skaters, home skaters, home goalie in net.
+    /// Returns `None` if the code is malformed.
+    pub fn from_situation_code(situation_code: &str, shooter_is_home: bool) -> Option<Self> {
+        let digits = situation_code
+            .chars()
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<_>>>()?;
+        let [away_goalie, away_skaters, home_skaters, home_goalie] = digits[..] else {
+            return None;
+        };
+
+        let (own_skaters, opposing_skaters, opposing_goalie) = if shooter_is_home {
+            (home_skaters, away_skaters, away_goalie)
+        } else {
+            (away_skaters, home_skaters, home_goalie)
+        };
+
+        Some(if opposing_goalie == 0 {
+            Strength::EmptyNet
+        } else if own_skaters > opposing_skaters {
+            Strength::PowerPlay
+        } else if own_skaters < opposing_skaters {
+            Strength::ShortHanded
+        } else {
+            Strength::Even
+        })
+    }
+}
+
+/// Coefficients applied to each [`ShotType`], relative to a wrist shot.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShotTypeCoefficients {
+    pub wrist: f64,
+    pub snap: f64,
+    pub slap: f64,
+    pub backhand: f64,
+    pub tip_in: f64,
+    pub deflected: f64,
+    pub wrap_around: f64,
+    pub other: f64,
+}
+
+/// Coefficients applied to each [`Strength`], relative to even strength.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StrengthCoefficients {
+    pub even: f64,
+    pub power_play: f64,
+    pub short_handed: f64,
+    pub empty_net: f64,
+}
+
+/// The coefficients of the logistic expected goals model.
+///
+/// Distances are in feet and angles in degrees. Can be (de)serialized as JSON
+/// so a model retrained offline can be loaded with [`XgModel::from_file`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XgCoefficients {
+    pub intercept: f64,
+    pub distance: f64,
+    pub angle: f64,
+    pub rebound: f64,
+    pub rush: f64,
+    pub shot_type: ShotTypeCoefficients,
+    pub strength: StrengthCoefficients,
+}
+
+impl Default for XgCoefficients {
+    fn default() -> Self {
+        Self {
+            intercept: -0.62,
+            distance: -0.0476,
+            angle: -0.011,
+            rebound: 0.92,
+            rush: 0.35,
+            shot_type: ShotTypeCoefficients {
+                wrist: 0.0,
+                snap: 0.05,
+                slap: 0.1,
+                backhand: -0.15,
+                tip_in: 0.1,
+                deflected: 0.05,
+                wrap_around: -0.4,
+                other: -0.2,
+            },
+            strength: StrengthCoefficients {
+                even: 0.0,
+                power_play: 0.3,
+                short_handed: 0.1,
+                empty_net: 3.0,
+            },
+        }
+    }
+}
+
+/// The inputs of the expected goals model for a single shot.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShotFeatures {
+    /// Distance to the attacked net in feet.
+    pub distance: f64,
+    /// Angle off the centre line of the attacked net in degrees.
+    pub angle: f64,
+    pub shot_type: ShotType,
+    pub is_rebound: bool,
+    pub is_rush: bool,
+    pub strength: Strength,
+}
+
+/// A shot scored by an [`XgModel`].
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShotXg {
+    pub event_id: i64,
+    pub period: i64,
+    pub seconds_in_period: i64,
+    pub team_id: i64,
+    pub shooter_id: Option<i64>,
+    pub is_goal: bool,
+    /// The normalized location of the shot.
+    pub point: RinkPoint,
+    pub features: ShotFeatures,
+    pub xg: f64,
+}
+
+/// Every scored shot of a game, with per team and per player totals.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameXg {
+    pub game_id: GameId,
+    pub shots: Vec<ShotXg>,
+}
+
+impl GameXg {
+    /// The expected goals of both teams combined.
+    pub fn total(&self) -> f64 {
+        self.shots.iter().map(|shot| shot.xg).sum()
+    }
+
+    /// The expected goals of a single team.
+    pub fn total_for_team(&self, team_id: i64) -> f64 {
+        self.shots
+            .iter()
+            .filter(|shot| shot.team_id == team_id)
+            .map(|shot| shot.xg)
+            .sum()
+    }
+
+    /// The expected goals of each team, keyed by team ID.
+    pub fn team_totals(&self) -> HashMap<i64, f64> {
+        let mut totals = HashMap::new();
+        for shot in &self.shots {
+            *totals.entry(shot.team_id).or_insert(0.0) += shot.xg;
+        }
+        totals
+    }
+
+    /// The expected goals of each shooter, keyed by player ID.
+    pub fn player_totals(&self) -> HashMap<i64, f64> {
+        let mut totals = HashMap::new();
+        for shot in &self.shots {
+            if let Some(shooter_id) = shot.shooter_id {
+                *totals.entry(shooter_id).or_insert(0.0) += shot.xg;
+            }
+        }
+        totals
+    }
+}
+
+/// A deterministic, logistic expected goals (xG) model.
+///
+/// Every unblocked shot attempt (goals, shots on goal and missed shots) in a
+/// play-by-play feed is scored from its distance, angle, shot type, rebound and
+/// rush flags and the manpower situation.
+///
+/// # Example
+/// ```
+/// use nhl_rs::{ShotFeatures, ShotType, Strength, XgModel};
+///
+/// let model = XgModel::default();
+///
+/// let slot = ShotFeatures {
+///     distance: 15.0,
+///     angle: 10.0,
+///     shot_type: ShotType::Wrist,
+///     is_rebound: false,
+///     is_rush: false,
+///     strength: Strength::Even,
+/// };
+/// let point = ShotFeatures {
+///     distance: 60.0,
+///     ..slot.clone()
+/// };
+///
+/// assert!(model.probability(&slot) > model.probability(&point));
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct XgModel {
+    pub coefficients: XgCoefficients,
+}
+
+impl XgModel {
+    /// Creates a new [`XgModel`] from a set of coefficients.
+    pub fn new(coefficients: XgCoefficients) -> Self {
+        Self { coefficients }
+    }
+
+    /// Loads [`XgCoefficients`] from a JSON file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not valid coefficients JSON.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        let coefficients = serde_json::from_str::<XgCoefficients>(&content)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Self::new(coefficients))
+    }
+
+    /// The probability, between 0 and 1, of a shot with the given features being a goal.
+    pub fn probability(&self, features: &ShotFeatures) -> f64 {
+        let c = &self.coefficients;
+
+        let shot_type = match features.shot_type {
+            ShotType::Wrist => c.shot_type.wrist,
+            ShotType::Snap => c.shot_type.snap,
+            ShotType::Slap => c.shot_type.slap,
+            ShotType::Backhand => c.shot_type.backhand,
+            ShotType::TipIn => c.shot_type.tip_in,
+            ShotType::Deflected => c.shot_type.deflected,
+            ShotType::WrapAround => c.shot_type.wrap_around,
+            ShotType::Other => c.shot_type.other,
+        };
+        let strength = match features.strength {
+            Strength::Even => c.strength.even,
+            Strength::PowerPlay => c.strength.power_play,
+            Strength::ShortHanded => c.strength.short_handed,
+            Strength::EmptyNet => c.strength.empty_net,
+        };
+
+        let logit = c.intercept
+            + c.distance * features.distance
+            + c.angle * features.angle
+            + if features.is_rebound { c.rebound } else { 0.0 }
+            + if features.is_rush { c.rush } else { 0.0 }
+            + shot_type
+            + strength;
+
+        1.0 / (1.0 + (-logit).exp())
+    }
+
+    /// Scores every unblocked shot attempt of a play-by-play feed.
+    ///
+    /// Shots without coordinates or an owning team are skipped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use nhl_rs::{ClientBuilder, GameId, XgModel};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), u16> {
+    /// let client = ClientBuilder::new().build();
+    /// let model = XgModel::default();
+    ///
+    /// let id = GameId::new(2023020204).unwrap();
+    /// let play_by_play = client.get_play_by_play(id).await?;
+    /// let game = model.score_game(&play_by_play);
+    ///
+    /// println!("Home xG: {:.2}", game.total_for_team(play_by_play.home_team.id));
+    /// println!("Away xG: {:.2}", game.total_for_team(play_by_play.away_team.id));
+    ///
+    /// Ok(())
+    /// # }
+    /// ```
+    pub fn score_game(&self, play_by_play: &PlayByPlayResponse) -> GameXg {
+        let home_team_id = play_by_play.home_team.id;
+        let mut shots = Vec::new();
+        let mut previous_event: Option<&Play> = None;
+        let mut previous_shot: Option<&Play> = None;
+
+        for play in &play_by_play.plays {
+            if play.is_unblocked_shot_attempt() {
+                if let Some(shot) =
+                    self.score_shot(play, previous_event, previous_shot, home_team_id)
+                {
+                    shots.push(shot);
+                }
+            }
+
+            if play.is_shot_attempt() {
+                previous_shot = Some(play);
+            }
+            if play
+                .details
+                .as_ref()
+                .is_some_and(|details| details.zone_code.is_some())
+            {
+                previous_event = Some(play);
+            }
+        }
+
+        GameXg {
+            game_id: play_by_play.id,
+            shots,
+        }
+    }
+
+    fn score_shot(
+        &self,
+        play: &Play,
+        previous_event: Option<&Play>,
+        previous_shot: Option<&Play>,
+        home_team_id: i64,
+    ) -> Option<ShotXg> {
+        let details = play.details.as_ref()?;
+        let team_id = details.event_owner_team_id?;
+        let point = RinkPoint::from_play(play, home_team_id)?;
+        let seconds = play.seconds_in_period()?;
+        let period = play.period_descriptor.number;
+        let shooter_is_home = team_id == home_team_id;
+
+        let within = |other: &Play, window: i64| {
+            other.period_descriptor.number == period
+                && other
+                    .seconds_in_period()
+                    .is_some_and(|other_seconds| seconds - other_seconds <= window)
+        };
+
+        let is_rebound = previous_shot.is_some_and(|shot| {
+            shot.team_id() == Some(team_id) && within(shot, REBOUND_WINDOW_SECONDS)
+        });
+        let is_rush = previous_event.is_some_and(|event| {
+            let zone = event
+                .details
+                .as_ref()
+                .and_then(|details| details.zone_code.as_deref());
+            let zone_for_shooter = match (zone, event.team_id() == Some(team_id)) {
+                (Some("O"), false) => Some("D"),
+                (Some("D"), false) => Some("O"),
+                (zone, _) => zone,
+            };
+            zone_for_shooter != Some("O") && within(event, RUSH_WINDOW_SECONDS)
+        });
+
+        let features = ShotFeatures {
+            distance: point.distance_to_net(),
+            angle: point.angle_to_net(),
+            shot_type: details
+                .shot_type
+                .as_deref()
+                .map(ShotType::from_api)
+                .unwrap_or(ShotType::Other),
+            is_rebound,
+            is_rush,
+            strength: play
+                .situation_code
+                .as_deref()
+                .and_then(|code| Strength::from_situation_code(code, shooter_is_home))
+                .unwrap_or_default(),
+        };
+
+        Some(ShotXg {
+            event_id: play.event_id,
+            period,
+            seconds_in_period: seconds,
+            team_id,
+            shooter_id: play.shooter_id(),
+            is_goal: play.type_desc_key == "goal",
+            point,
+            xg: self.probability(&features),
+            features,
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use std::{fs, path::PathBuf};
+
+use nhl_rs::{
+    Cassette, Client, ClientBuilder, EdgeSelection, TeamId, Transport, CASSETTE_ERROR, NO_CASSETTE,
+};
+
+fn cassettes() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/cassettes")
+}
+
+fn replay_client() -> Client {
+    ClientBuilder::new()
+        .transport(Transport::Replay(cassettes()))
+        .build()
+}
+
+#[tokio::test]
+async fn ping() {
+    assert!(replay_client().ping().await.unwrap().success);
+}
+
+#[tokio::test]
+async fn countries() {
+    let client = replay_client();
+
+    let countries = client.get_countries().await.unwrap();
+    let finland = client.get_country_by_country_name("finland").await.unwrap();
+
+    assert_eq!(countries.len(), 2);
+    assert_eq!(finland.unwrap().country3code, "FIN");
+}
+
+#[tokio::test]
+async fn franchises_by_name() {
+    let client = replay_client();
+
+    let canadiens = client
+        .get_franchise_by_full_name("Montreal Canadiens")
+        .await
+        .unwrap();
+    let blues = client
+        .get_franchise_by_team_place_name("St Louis")
+        .await
+        .unwrap();
+
+    assert_eq!(canadiens.unwrap().id, 1);
+    assert_eq!(blues.unwrap().id, 18);
+}
+
+#[tokio::test]
+async fn teams() {
+    let client = replay_client();
+
+    let teams = client.get_teams().await.unwrap();
+    let north_stars = client.get_team_by_tri_code("mns").await.unwrap();
+
+    assert_eq!(teams.len(), 3);
+    assert_eq!(north_stars.unwrap().franchise_id, Some(15));
+}
+
+#[tokio::test]
+async fn recorded_errors_are_replayed() {
+    let team = TeamId::new(99).unwrap();
+
+    let response = replay_client()
+        .get_team_edge(team, EdgeSelection::Now)
+        .await;
+
+    assert_eq!(response, Err(404));
+}
+
+#[tokio::test]
+async fn missing_cassettes_are_not_http_errors() {
+    let response = replay_client().get_seasons().await;
+
+    assert_eq!(response, Err(NO_CASSETTE));
+}
+
+#[tokio::test]
+async fn corrupt_cassettes_are_reported() {
+    let dir = std::env::temp_dir().join("nhl-rs-corrupt-cassette");
+    fs::create_dir_all(&dir).unwrap();
+    let url = "https://api.nhle.com/stats/rest/ping";
+    fs::write(Cassette::path(&dir, "GET", url), "{").unwrap();
+    let client = ClientBuilder::new()
+        .transport(Transport::Replay(dir.clone()))
+        .build();
+
+    assert!(Cassette::load(&dir, "GET", url).is_err());
+    assert_eq!(client.ping().await, Err(CASSETTE_ERROR));
+}
@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+
+use nhl_rs::{Cassette, ClientBuilder, Country, FieldType, SchemaDrift, Transport};
+
+fn cassettes() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/cassettes")
+}
+
+#[tokio::test]
+async fn recorded_metadata_matches_every_model() {
+    let client = ClientBuilder::new()
+        .transport(Transport::Replay(cassettes()))
+        .build();
+
+    let drifts = client.check_all_schemas().await;
+
+    assert!(drifts.is_empty(), "{:#?}", drifts);
+}
+
+#[tokio::test]
+async fn retyped_optional_field_is_reported() {
+    let client = ClientBuilder::new()
+        .transport(Transport::Replay(cassettes()))
+        .build();
+    let mut metadata = client.get_metadata("country").await.unwrap();
+    for property in &mut metadata.entity.properties {
+        if property.name == "imageUrl" {
+            property.type_field = "integer".to_string();
+        }
+    }
+
+    let drift = SchemaDrift::check::<Country>(&metadata);
+
+    assert_eq!(drift.retyped_fields.len(), 1);
+    assert_eq!(drift.retyped_fields[0].name, "imageUrl");
+    assert_eq!(drift.retyped_fields[0].expected, FieldType::String);
+    assert_eq!(drift.retyped_fields[0].actual, FieldType::Integer);
+}
+
+#[tokio::test]
+async fn failing_resources_do_not_stop_the_others() {
+    let dir = std::env::temp_dir().join("nhl-rs-schema-partial");
+    let url = "https://api.nhle.com/stats/rest/en/country/meta";
+    Cassette::load(&cassettes(), "GET", url)
+        .unwrap()
+        .unwrap()
+        .save(&dir)
+        .unwrap();
+    let client = ClientBuilder::new()
+        .transport(Transport::Replay(dir))
+        .build();
+
+    let drifts = client.check_all_schemas().await;
+
+    assert_eq!(drifts.len(), 10);
+    assert!(!drifts.contains_key("country"));
+    assert!(drifts.values().all(|result| result.is_err()));
+}
@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+use nhl_rs::{stats::SkaterSummary, Client, ClientBuilder, StatsQuery, Transport, NO_CASSETTE};
+
+fn replay_client() -> Client {
+    let cassettes = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/cassettes");
+    ClientBuilder::new()
+        .transport(Transport::Replay(cassettes))
+        .build()
+}
+
+#[tokio::test]
+async fn valid_dates_are_requested() {
+    let query = StatsQuery::new().dates("2024-02-29", "2024-03-01");
+
+    let response = replay_client().get_stats::<SkaterSummary>(&query).await;
+
+    assert_eq!(response.err(), Some(NO_CASSETTE));
+}
+
+#[tokio::test]
+async fn invalid_dates_are_rejected() {
+    let client = replay_client();
+
+    for (from, to) in [
+        ("2024-02-30", "2024-03-01"),
+        ("2024-1-01", "2024-01-31"),
+        ("2024-01-31", "2024-01-01"),
+    ] {
+        let query = StatsQuery::new().dates(from, to);
+        let response = client.get_stats::<SkaterSummary>(&query).await;
+
+        assert_eq!(response.err(), Some(400), "{} to {}", from, to);
+    }
+}